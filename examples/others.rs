@@ -15,7 +15,7 @@ fn main() {
 	let ms = ms.replace("$", "#");
 	println!("modified String: {ms}");
 	
-	let m = Money::from_str(&ms).unwrap();
+	let m = Money::from_str_with_symbols(&ms, &['#']).unwrap();
 	println!("as Money (from String): {m}");
 	println!();
 	
@@ -2,5 +2,13 @@
 
 pub mod money;
 
-pub use money::{Money, MoneySign, MoneyErrorCents, MoneyErrorString, MoneyErrorOverflow};
-pub use money::options::NegativeView;
\ No newline at end of file
+pub use money::{Money, MoneySign, Receipt, MoneyErrorCents, MoneyErrorString, ParseMoneyError, MoneyErrorOverflow, MoneyErrorNegative, MoneyError, FieldError, ChangeError, DEFAULT_ACCEPTED_SYMBOLS};
+pub use money::amount::Amount;
+pub use money::currency::Currency;
+pub use money::rounding::{RoundingMode, Rounder};
+pub use money::builder::MoneyBuilder;
+pub use money::locale::Locale;
+pub use money::fixed::FixedMoney;
+pub use money::parse_options::ParseOptions;
+pub use money::interest::DayCount;
+pub use money::options::{NegativeView, NegativeSignPosition, SymbolPosition, GroupingStyle, OptionsSnapshot};
\ No newline at end of file
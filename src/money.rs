@@ -1,12 +1,44 @@
 pub mod options;
+pub mod amount;
+pub mod currency;
+pub mod rounding;
+pub mod builder;
+pub mod locale;
+pub mod fixed;
+pub mod parse_options;
+pub mod interest;
+mod parse;
+mod allocation;
+mod expression;
+mod format;
+mod tax;
 
-use options::{Options, NegativeView};
+pub use tax::Receipt;
+
+use options::{Options, NegativeView, NegativeSignPosition, SymbolPosition, GroupingStyle};
+use currency::Currency;
+use rounding::{RoundingMode, Rounder};
+use builder::MoneyBuilder;
+use parse_options::ParseOptions;
 use std::ops::{Add, AddAssign, Sub, SubAssign, Neg};
+use std::iter::{Sum, FromIterator};
 use std::cmp::{PartialEq, Ordering};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 use std::error::Error;
 
+/// The symbols recognized by `Money::from_str()`: common currency symbols.
+pub const DEFAULT_ACCEPTED_SYMBOLS: [char; 26] = [
+	'$', '€', '£', '¥', '₹', '¢', '₩', '₽', '₪', '₺', '₫', '₱', '₴',
+	'₦', '฿', '₡', '₲', '₵', '₸', '₭', '₮', '₼', '₾', '₿', '₣', '¤',
+];
+
+const RTL_MARK: char = '\u{200F}';
+
+/* the number of minor digits held by the 'cents' field */
+const STORED_MINOR_DIGITS: u32 = 2;
+
 #[derive(Debug, Clone)]
 pub struct MoneyErrorCents;
 
@@ -40,6 +72,156 @@ impl fmt::Display for MoneyErrorOverflow {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct MoneyErrorNegative;
+
+impl Error for MoneyErrorNegative {}
+
+impl fmt::Display for MoneyErrorNegative {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "value is negative")
+    }
+}
+
+/// Returned by `Money::make_change()` when the amount can't be made exactly from the denominations.
+#[derive(Debug, Clone)]
+pub struct ChangeError;
+
+impl Error for ChangeError {}
+
+impl fmt::Display for ChangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "exact change is not possible")
+    }
+}
+
+/// Returned by `Money::parse_with()`, naming what was rejected so that the `ParseOptions` rule
+/// responsible can be told apart. Converts to `MoneyErrorString`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseMoneyError {
+	/// There are no digits, or the digits and separators don't form a number that fits. This includes
+	/// a decimal separator set by `ParseOptions::decimal_separator()` followed by three digits.
+	Number,
+	/// A lone '.' is followed by three digits, which is only read as grouping if allowed by
+	/// `ParseOptions::allow_ambiguous_grouping()`.
+	Ambiguous,
+	/// The symbol isn't in `ParseOptions::accepted_symbols()` (or a code allowed by
+	/// `ParseOptions::allow_codes()`), or there is more than one. An accounting suffix is read as a
+	/// symbol if `ParseOptions::allow_entry_suffix()` is off.
+	Symbol,
+	/// There is more than one sign marker, or one that conflicts with parentheses or an accounting suffix.
+	Sign,
+	/// A sign marker follows the number, which `ParseOptions::allow_suffix_sign()` disallows.
+	SuffixSign,
+	/// The parentheses are unbalanced or misplaced, or disallowed by `ParseOptions::allow_parentheses()`.
+	Parentheses,
+}
+
+impl Error for ParseMoneyError {}
+
+impl fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ParseMoneyError::Number => { write!(f, "invalid number") },
+			ParseMoneyError::Ambiguous => { write!(f, "ambiguous decimal or grouping separator") },
+			ParseMoneyError::Symbol => { write!(f, "symbol not accepted") },
+			ParseMoneyError::Sign => { write!(f, "invalid sign marker") },
+			ParseMoneyError::SuffixSign => { write!(f, "sign marker after the number not allowed") },
+			ParseMoneyError::Parentheses => { write!(f, "invalid or disallowed parentheses") },
+		}
+    }
+}
+
+impl From<ParseMoneyError> for MoneyErrorString {
+	fn from(_: ParseMoneyError) -> Self {
+		MoneyErrorString
+	}
+}
+
+/// Errors returned by operations that can fail in more than one way.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MoneyError {
+	Cents,
+	String,
+	Overflow,
+	DivideByZero,
+	NotFinite,
+	Scale,
+	Symbol,
+	CurrencyMismatch,
+	SignMismatch,
+	Negative,
+}
+
+impl Error for MoneyError {}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			MoneyError::Cents => { write!(f, "{}", MoneyErrorCents) },
+			MoneyError::String => { write!(f, "{}", MoneyErrorString) },
+			MoneyError::Overflow => { write!(f, "operation has resulted in overflow") },
+			MoneyError::DivideByZero => { write!(f, "division by zero") },
+			MoneyError::NotFinite => { write!(f, "value is not a finite number") },
+			MoneyError::Scale => { write!(f, "unsupported number of minor digits") },
+			MoneyError::Symbol => { write!(f, "invalid money symbol") },
+			MoneyError::CurrencyMismatch => { write!(f, "operands have different currencies") },
+			MoneyError::SignMismatch => { write!(f, "operands have different signs") },
+			MoneyError::Negative => { write!(f, "{}", MoneyErrorNegative) },
+		}
+    }
+}
+
+impl From<MoneyErrorCents> for MoneyError {
+	fn from(_: MoneyErrorCents) -> Self {
+		MoneyError::Cents
+	}
+}
+
+impl From<MoneyErrorString> for MoneyError {
+	fn from(_: MoneyErrorString) -> Self {
+		MoneyError::String
+	}
+}
+
+impl From<ParseMoneyError> for MoneyError {
+	fn from(_: ParseMoneyError) -> Self {
+		MoneyError::String
+	}
+}
+
+impl From<MoneyErrorOverflow> for MoneyError {
+	fn from(_: MoneyErrorOverflow) -> Self {
+		MoneyError::Overflow
+	}
+}
+
+impl From<MoneyErrorNegative> for MoneyError {
+	fn from(_: MoneyErrorNegative) -> Self {
+		MoneyError::Negative
+	}
+}
+
+/// A field of a Money instance holding an invalid value, reported by `Money::validate()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldError {
+	/// The `cents` field is 100 or more.
+	CentsOutOfRange(u8),
+	/// The `sign` field is negative while the amount is zero.
+	NegativeZero,
+}
+
+impl Error for FieldError {}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			FieldError::CentsOutOfRange(cents) => { write!(f, "cents field is out of range: {}", cents) },
+			FieldError::NegativeZero => { write!(f, "sign field is negative for a zero amount") },
+		}
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum MoneySign {
 	Positive,
@@ -88,6 +270,60 @@ impl Money {
 		}
 	}
 	
+	/// Creates a new Money instance, carrying any cents beyond 99 into the dollars.
+	///
+	/// Returns an error if the carried dollars overflow.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new_carrying(5, 150, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(m.to_string(), "$6.50");
+	/// assert!(Money::new_carrying(u64::MAX, 100, MoneySign::Positive).is_err());
+	/// ```
+	pub fn new_carrying(dollars: u64, cents: u64, sign: MoneySign) -> Result<Self, MoneyErrorOverflow> {
+		let dollars = dollars.checked_add(cents / 100).ok_or(MoneyErrorOverflow)?;
+		
+		Ok(Self::new(dollars, (cents % 100) as u8, sign).unwrap())
+	}
+	
+	/// Creates a new Money instance from a major and minor amount at the given `scale`, where
+	/// `minor` counts units of 10<sup>-scale</sup>.
+	///
+	/// Returns an error if `minor` is not less than 10<sup>scale</sup>, or if it can't be held
+	/// exactly as cents (e.g. a scale of 3 with a non-zero last digit).
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::checked_from_parts(5, 250, 3, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(m, Money::new(5, 25, MoneySign::Positive).unwrap());
+	/// assert!(Money::checked_from_parts(5, 1000, 3, MoneySign::Positive).is_err());
+	/// ```
+	pub fn checked_from_parts(major: u64, minor: u64, scale: u32, sign: MoneySign) -> Result<Self, MoneyErrorCents> {
+		let cents = convert_minor_to_cents(minor as u128, scale)?;
+		
+		Self::new(major, cents, sign)
+	}
+	
+	/// Returns a builder for constructing a Money instance and its options together.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::builder().dollars(5).cents(25).symbol('£').build().unwrap();
+	///
+	/// assert_eq!(m.to_string(), "£5.25");
+	/// ```
+	pub fn builder() -> MoneyBuilder {
+		MoneyBuilder::new()
+	}
+	
 	/// Returns the `dollars` value of the Money instance.
 	pub fn dollars(&self) -> u64 {
 		self.dollars
@@ -108,6 +344,22 @@ impl Money {
 		&mut self.options
 	}
 	
+	/// Restores every option to its default.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// m.options().set_show_symbol(false);
+	/// m.reset_options();
+	///
+	/// assert_eq!(m.to_string(), "$5.25");
+	/// ```
+	pub fn reset_options(&mut self) {
+		self.options.reset();
+	}
+	
 	fn options_immutable(&self) -> &Options {
 		&self.options
 	}
@@ -127,626 +379,4424 @@ impl Money {
 		convert_money_to_whole(self)
 	}
 	
-	/// Returns the cents as a Money instance.
+	/// Returns the whole dollars as a `u32`. The cents are available separately from `cents()`.
+	///
+	/// Returns `MoneyError::Negative` if the amount is negative, even by less than a dollar, or
+	/// `MoneyError::Overflow` if the dollars don't fit.
 	///
 	/// # Example
 	///
 	/// ```
-	/// # use nmoney::{Money, MoneySign};
-	/// let m = Money::from_cents(-525);
+	/// # use nmoney::{Money, MoneySign, MoneyError};
+	/// let m = Money::new(5, 25, MoneySign::Positive).unwrap();
 	///
-	/// assert_eq!(m.to_string(), "-$5.25");
+	/// assert_eq!(m.as_dollars_u32().unwrap(), 5);
+	/// assert_eq!(m.cents(), 25);
+	/// assert_eq!((-m).as_dollars_u32(), Err(MoneyError::Negative));
 	/// ```
-	pub fn from_cents(cents: i64) -> Money {
-		convert_whole_to_money(cents)
+	pub fn as_dollars_u32(&self) -> Result<u32, MoneyError> {
+		if self.sign == MoneySign::Negative && (self.dollars != 0 || self.cents != 0) {
+			return Err(MoneyError::Negative);
+		}
+		
+		u32::try_from(self.dollars).map_err(|_| MoneyError::Overflow)
 	}
 	
-	/// Converts a string into a Money type.
+	/// Returns the signed whole dollars as an `i32`, or an error if they don't fit. The signed cents
+	/// are available separately from `as_minor_i8()`.
 	///
 	/// # Example
 	///
 	/// ```
 	/// # use nmoney::{Money, MoneySign};
-	/// let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
-	/// let m2 = Money::from_str("5.25").unwrap();
+	/// let m = Money::new(5, 25, MoneySign::Negative).unwrap();
 	///
-	/// assert_eq!(m1, m2);
+	/// assert_eq!(m.as_dollars_i32().unwrap(), -5);
+	/// assert_eq!(m.as_minor_i8(), -25);
 	/// ```
-	pub fn from_str(s: &str) -> Result<Self, MoneyErrorString> {
-		let mut sign = MoneySign::Positive;
-		let mut is_paren = false;
-		let mut symbol = None;
-		let mut r = String::from(s);
-		
-		// check for negative
-		if r.starts_with("-") {
-			sign = MoneySign::Negative;
-			let _ = r.remove(0);
-		} else if r.starts_with("(") {
-			if r.ends_with(")") {
-				sign = MoneySign::Negative;
-				is_paren = true;
-				let _ = r.remove(0);
-				let _ = r.pop();
-			} else {
-				return Err(MoneyErrorString);
-			}
-		}
-		
-		// check for symbol
-		let leading = r.remove(0);
-		
-		if leading.is_ascii_digit() {
-			r.insert(0, leading);
-		} else {
-			symbol = Some(leading);
-		}
+	pub fn as_dollars_i32(&self) -> Result<i32, MoneyErrorOverflow> {
+		let dollars = if self.sign == MoneySign::Negative { -(self.dollars as i128) } else { self.dollars as i128 };
 		
-		// break apart string
-		let v: Vec<_> = r.split(".").collect();
-		
-		if v.len() != 2 {
-			return Err(MoneyErrorString);
-		}
-		
-		// convert vec elements
-		let d = match v[0].parse::<u64>() {
-			Ok(r) => { r },
-			Err(_) => { return Err(MoneyErrorString); },
-		};
-		
-		let c = match v[1].parse::<u8>() {
-			Ok(r) => { r },
-			Err(_) => { return Err(MoneyErrorString); },
-		};
-
-		if c >= 100 {
-			return Err(MoneyErrorString);
-		}
-		
-		let mut m = Money::new(d, c, sign).unwrap();
-		
-		if is_paren {
-			m.options().set_negative_view(NegativeView::Paren);
-		}
-		
-		if let Some(sym) = symbol {
-			m.options().set_symbol(sym);
+		i32::try_from(dollars).map_err(|_| MoneyErrorOverflow)
+	}
+	
+	/// Returns the cents with the sign of the amount, which always fit in an `i8`.
+	pub fn as_minor_i8(&self) -> i8 {
+		if self.sign == MoneySign::Negative {
+			-(self.cents as i8)
 		} else {
-			m.options().set_show_symbol(false);
+			self.cents as i8
 		}
-		
-		Ok(m)
 	}
 	
-	/// Copies the `options` of `src` to `dest`.
+	/// Returns the magnitude of the Money instance, keeping its options.
+	///
+	/// The magnitude is always representable, so this can't overflow.
 	///
 	/// # Example
 	///
 	/// ```
 	/// # use nmoney::{Money, MoneySign};
-	/// # use nmoney::money::options::NegativeView;
-	/// let mut m1 = Money::new(59, 99, MoneySign::Negative).unwrap();
-	/// m1.options().set_symbol('#');
-	/// m1.options().set_negative_view(NegativeView::Paren);
+	/// let m = Money::new(5, 25, MoneySign::Negative).unwrap();
 	///
-	/// let mut m2 = Money::new(1098, 54, MoneySign::Negative).unwrap();
-	/// Money::copy_options(&mut m2, &m1);
+	/// assert_eq!(m.abs().to_string(), "$5.25");
+	/// ```
+	pub fn abs(&self) -> Money {
+		let mut m = *self;
+		m.sign = MoneySign::Positive;
+		m
+	}
+	
+	/// Returns the magnitude of the Money instance in cents, or an error if it doesn't fit in an `i64`.
+	///
+	/// Note that `as_cents()` succeeds for `i64::MIN` cents, whose magnitude can't be represented.
+	///
+	/// # Example
 	///
-	/// assert_eq!(m2.to_string(), "(#1098.54)");
 	/// ```
-	pub fn copy_options(dest: &mut Money, src: &Money) {
-		dest.options = src.options;
+	/// # use nmoney::Money;
+	/// assert_eq!(Money::from_cents(-525).abs_cents().unwrap(), 525);
+	/// assert!(Money::from_cents(i64::MIN).abs_cents().is_err());
+	/// ```
+	pub fn abs_cents(&self) -> Result<i64, MoneyErrorOverflow> {
+		convert_money_to_whole(&self.abs())
 	}
-}
-
-fn convert_money_to_whole(money: &Money) -> Result<i64, MoneyErrorOverflow> {
-	let dollars: i64 = (money.dollars * 100) as i64;
-	let cents: i64 = (money.cents) as i64;
 	
-	match dollars.checked_add(cents) {
-		Some(mut sum) => {
-			if money.sign == MoneySign::Negative {
-				sum *= -1;
-			}
-			Ok(sum)
-		},
-		None => {
-			Err(MoneyErrorOverflow)
-		},
+	/// Returns the signed total number of cents as an `i128`, which orders the same as `Ord` and
+	/// can't overflow, for use as a sort or index key.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// assert_eq!(Money::from_cents(-525).sort_key(), -525);
+	/// assert!(Money::min_representable().sort_key() < Money::max_representable().sort_key());
+	/// ```
+	pub fn sort_key(&self) -> i128 {
+		convert_money_to_wide(self)
 	}
-}
-
-fn convert_whole_to_money(mut whole: i64) -> Money {
-	let mut sign = MoneySign::Positive;
 	
-	if whole < 0 {
-		sign = MoneySign::Negative;
-		whole *= -1;
+	/// Returns the magnitude of the Money instance in cents, along with its sign.
+	///
+	/// Unlike `as_cents()`, this can't overflow, so it's suitable for lossless storage of any value.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(u64::MAX, 99, MoneySign::Negative).unwrap();
+	///
+	/// assert!(m.as_cents().is_err());
+	/// assert_eq!(m.to_minor_units(), (u64::MAX as u128 * 100 + 99, MoneySign::Negative));
+	/// ```
+	pub fn to_minor_units(&self) -> (u128, MoneySign) {
+		(self.dollars as u128 * 100 + self.cents as u128, self.sign)
 	}
 	
-	Money {
-		dollars: (whole / 100) as u64,
-		cents: (whole % 100) as u8,
-		sign,
-		options: Options::new()
+	/// Creates a new Money instance from a magnitude in minor units at the given `scale`, and a sign.
+	///
+	/// This is the inverse of `to_minor_units()` (with a `scale` of 2). Returns `MoneyError::Overflow`
+	/// if the major part doesn't fit in the `u64` dollars, `MoneyError::Scale` if `scale` is too
+	/// large, or `MoneyError::Cents` if the minor part can't be held exactly as cents.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::from_minor_units(5250, MoneySign::Negative, 3).unwrap();
+	///
+	/// assert_eq!(m.to_string(), "-$5.25");
+	/// assert!(Money::from_minor_units(5255, MoneySign::Negative, 3).is_err());
+	/// ```
+	pub fn from_minor_units(magnitude: u128, sign: MoneySign, scale: u32) -> Result<Self, MoneyError> {
+		let limit = 10u128.checked_pow(scale).ok_or(MoneyError::Scale)?;
+		let major = u64::try_from(magnitude / limit).map_err(|_| MoneyError::Overflow)?;
+		let cents = convert_minor_to_cents(magnitude % limit, scale)?;
+		
+		Ok(Self::new(major, cents, sign)?)
 	}
-}
-
-impl Default for Money {
-    fn default() -> Self {
-		Self {
-			dollars: 0,
-			cents: 0,
-			sign: MoneySign::Positive,
-			options: Options::new(),
+	
+	/// Creates a new Money instance from a magnitude in cents and a sign, clamping to
+	/// `max_representable()` or `min_representable()` if the dollars don't fit.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// assert_eq!(Money::from_minor_units_saturating(525, MoneySign::Negative), Money::from_cents(-525));
+	/// assert_eq!(Money::from_minor_units_saturating(u128::MAX, MoneySign::Positive), Money::max_representable());
+	/// ```
+	pub fn from_minor_units_saturating(magnitude: u128, sign: MoneySign) -> Money {
+		match Self::from_minor_units(magnitude, sign, STORED_MINOR_DIGITS) {
+			Ok(m) => { m },
+			Err(_) if sign == MoneySign::Negative => { Money::min_representable() },
+			Err(_) => { Money::max_representable() },
 		}
 	}
-}
-
-impl Add for Money {
-	type Output = Self;
 	
-	fn add(self, other: Self) -> Self {
-		let whole_1 = convert_money_to_whole(&self).expect("overflow on addition");
-		let whole_2 = convert_money_to_whole(&other).expect("overflow on addition");
-		
-		match whole_1.checked_add(whole_2) {
-			Some(sum) => {
-				convert_whole_to_money(sum)
-			},
-			None => {
-				panic!("overflow on addition");
-			},
-		}
+	/// Returns a positive whole number of dollars as a Money instance.
+	///
+	/// Named `from_dollars` as `dollars()` returns the `dollars` value.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// assert_eq!(Money::from_dollars(5).to_string(), "$5.00");
+	/// ```
+	pub fn from_dollars(dollars: u64) -> Money {
+		Self::new(dollars, 0, MoneySign::Positive).unwrap()
 	}
-}
-
-impl AddAssign for Money {
-    fn add_assign(&mut self, other: Self) {
-        *self = *self + other;
-    }
-}
-
-impl Sub for Money {
-	type Output = Self;
 	
-	fn sub(self, other: Self) -> Self {
-		let whole_1 = convert_money_to_whole(&self).expect("overflow on subtraction");
-		let whole_2 = convert_money_to_whole(&other).expect("overflow on subtraction");
+	/// Returns a signed whole number of dollars as a Money instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// assert_eq!(Money::from_dollars_signed(-5).to_string(), "-$5.00");
+	/// ```
+	pub fn from_dollars_signed(dollars: i64) -> Money {
+		let sign = if dollars < 0 { MoneySign::Negative } else { MoneySign::Positive };
+		
+		Self::new(dollars.unsigned_abs(), 0, sign).unwrap()
+	}
+	
+	/// Returns the cents as a Money instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::from_cents(-525);
+	///
+	/// assert_eq!(m.to_string(), "-$5.25");
+	/// ```
+	pub fn from_cents(cents: i64) -> Money {
+		convert_whole_to_money(cents)
+	}
+	
+	/// Converts a string of total cents, grouped with `separator`, into a Money type.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::from_cents_grouped("1,050,000", ',').unwrap();
+	///
+	/// assert_eq!(m, Money::new(10500, 0, MoneySign::Positive).unwrap());
+	/// ```
+	pub fn from_cents_grouped(s: &str, separator: char) -> Result<Self, MoneyErrorString> {
+		let r: String = s.chars().filter(|c| *c != separator).collect();
+		
+		match r.parse::<i64>() {
+			Ok(cents) => { Ok(convert_whole_to_money(cents)) },
+			Err(_) => { Err(MoneyErrorString) },
+		}
+	}
+	
+	/// Converts a string into a Money type.
+	///
+	/// Any string produced by `Display` parses back into an equal value whose options display the
+	/// same string, provided the negative view is not `Hide`, `minor_digits` is not set, the symbol
+	/// contains exactly one of `DEFAULT_ACCEPTED_SYMBOLS` with any other characters being letters
+	/// (e.g. "US$") or combining marks, or is a code of three to five capital letters (e.g. "CHF"
+	/// or "BTC"), the decimal separator ('.' or ',') differs from the
+	/// grouping separator ('.', ',', an apostrophe or a space), and a whole amount isn't grouped
+	/// by a single '.' (e.g. "1.234").
+	///
+	/// A separator followed by one or two digits is the decimal separator, and one followed by
+	/// three digits is a grouping separator. However, a lone '.' followed by three digits is taken
+	/// as a decimal point, so `"5.123"` is rejected; `from_str_smart()` reads it as grouping.
+	/// Without cents, the `hide_zero_cents` option is set.
+	///
+	/// An accounting suffix of `" CR"` (credit) or `" DR"` (debit), in any case, may be used in
+	/// place of a sign, so `"5.00 DR"` is negative. The Unicode minus sign (U+2212) is read as '-'.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// let m2 = Money::from_str("5.25").unwrap();
+	///
+	/// assert_eq!(m1, m2);
+	///
+	/// let m3 = Money::from_str("(£1,098.54)").unwrap();
+	///
+	/// assert_eq!(m3.to_string(), "(£1,098.54)");
+	/// ```
+	#[allow(clippy::should_implement_trait)]
+	pub fn from_str(s: &str) -> Result<Self, MoneyErrorString> {
+		parse::parse_money(s, &ParseOptions::default()).map_err(MoneyErrorString::from)
+	}
+	
+	/// Converts a string into a Money type like `from_str()`, recognizing only the symbols in
+	/// `accepted_symbols` rather than `DEFAULT_ACCEPTED_SYMBOLS`. Any other symbol is an error.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// assert!(Money::from_str("#5.25").is_err());
+	///
+	/// let m = Money::from_str_with_symbols("#5.25", &['#']).unwrap();
+	///
+	/// assert_eq!(m.to_string(), "#5.25");
+	/// assert!(Money::from_str_with_symbols("$5.25", &['#']).is_err());
+	/// ```
+	pub fn from_str_with_symbols(s: &str, accepted_symbols: &[char]) -> Result<Self, MoneyErrorString> {
+		parse::parse_money(s, &ParseOptions::new().accepted_symbols(accepted_symbols)).map_err(MoneyErrorString::from)
+	}
+	
+	/// Converts a string into a Money type like `from_str()`, with the forms, symbols and separators
+	/// accepted configured by `opts`. The error says which part of the string was rejected.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, ParseOptions, ParseMoneyError};
+	/// let opts = ParseOptions::new().decimal_separator(',');
+	///
+	/// assert_eq!(Money::parse_with("€1.234", &opts).unwrap(), Money::from_cents(123400));
+	/// assert_eq!(Money::from_str("€1.23").unwrap(), Money::from_cents(123));
+	/// assert_eq!(Money::parse_with("(€1.234)", &opts.allow_parentheses(false)), Err(ParseMoneyError::Parentheses));
+	/// ```
+	pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, ParseMoneyError> {
+		parse::parse_money(s, opts)
+	}
+	
+	/// Converts a string into a Money type, inferring whether ',' or '.' is the decimal separator.
+	///
+	/// The last separator is the decimal separator if it is followed by one or two digits; any other
+	/// separators are grouping separators. Consequently, a separator followed by three digits is
+	/// always taken as grouping, so `"1.234"` is one thousand two hundred thirty-four dollars, never
+	/// one dollar and change. Amounts with more than two decimal places cannot be parsed.
+	///
+	/// Grouping may also use a space (including non-breaking spaces), as in `"1 234,56"`. Grouping must be
+	/// in thousands or Indian style. The separators and grouping style found are set in the options.
+	///
+	/// `from_str()` applies the same inference, except that it rejects a lone '.' followed by three
+	/// digits, which this function reads as grouping.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m1 = Money::from_str_smart("1.234,56").unwrap();
+	/// let m2 = Money::from_str_smart("$1,234.56").unwrap();
+	///
+	/// assert_eq!(m1, Money::new(1234, 56, MoneySign::Positive).unwrap());
+	/// assert_eq!(m1, m2);
+	///
+	/// assert_eq!(Money::from_str_smart("€1.234").unwrap(), Money::from_dollars(1234));
+	/// assert!(Money::from_str("€1.234").is_err());
+	/// ```
+	pub fn from_str_smart(s: &str) -> Result<Self, MoneyErrorString> {
+		parse::parse_money(s, &ParseOptions::new().allow_ambiguous_grouping(true)).map_err(MoneyErrorString::from)
+
+	}
+	
+	/// Converts a string prefixed with a three-letter currency code (e.g. `"USD 5.25"`) into a Money type.
+	///
+	/// The code sets the currency and symbol of the options. The amount may use either '.' or ','
+	/// as the decimal separator, and may omit the cents. Currencies without minor units (e.g. JPY)
+	/// do not accept cents, and are shown without them. Unknown codes return an error.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::from_str_with_code("EUR 5,25").unwrap();
+	///
+	/// assert_eq!(m, Money::new(5, 25, MoneySign::Positive).unwrap());
+	/// assert_eq!(m.to_string(), "€5,25");
+	/// ```
+	pub fn from_str_with_code(s: &str) -> Result<Self, MoneyErrorString> {
+		let s = parse::trim_input(s);
+		
+		let (code, r) = match s.split_once(char::is_whitespace) {
+			Some(parts) => { parts },
+			None => { return Err(MoneyErrorString); },
+		};
+		
+		if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+			return Err(MoneyErrorString);
+		}
+		
+		let currency = match Currency::from_code(code) {
+			Some(c) => { c },
+			None => { return Err(MoneyErrorString); },
+		};
+		
+		let mut r = r.trim_start();
+		let mut sign = MoneySign::Positive;
+		
+		if let Some(rest) = r.strip_prefix('-') {
+			sign = MoneySign::Negative;
+			r = rest;
+		}
+		
+		let (whole, fraction, separator) = match r.find(['.', ',']) {
+			Some(i) => { (&r[..i], Some(&r[i + 1..]), r[i..].chars().next()) },
+			None => { (r, None, None) },
+		};
+		
+		if whole.is_empty() || !whole.chars().all(|c| c.is_ascii_digit()) {
+			return Err(MoneyErrorString);
+		}
+		
+		let d = match whole.parse::<u64>() {
+			Ok(r) => { r },
+			Err(_) => { return Err(MoneyErrorString); },
+		};
+		
+		let c = match fraction {
+			None => { 0 },
+			Some(f) => {
+				if currency.minor_digits() == 0 ||
+					f.is_empty() || f.len() > 2 ||
+					!f.chars().all(|c| c.is_ascii_digit()) {
+					return Err(MoneyErrorString);
+				}
+				
+				let c = f.parse::<u8>().unwrap();
+				
+				if f.len() == 1 { c * 10 } else { c }
+			},
+		};
+		
+		let mut m = Money::new(d, c, sign).unwrap();
+		m.options().set_currency(Some(currency));
+		m.options().set_symbol(currency.symbol());
+		
+		if currency.minor_digits() != STORED_MINOR_DIGITS {
+			m.options().set_minor_digits(Some(currency.minor_digits()));
+		}
+		
+		if let Some(sep) = separator {
+			m.options().set_decimal_separator(sep);
+		}
+		
+		Ok(m)
+	}
+	
+	/// Returns the running totals after each of `n` payments of this amount, or an error if an overflow has occurred.
+	///
+	/// The totals keep the options of the Money instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(9, 99, MoneySign::Positive).unwrap();
+	/// let totals: Vec<String> = m.repeat(3).unwrap().iter().map(|t| t.to_string()).collect();
+	///
+	/// assert_eq!(totals, ["$9.99", "$19.98", "$29.97"]);
+	/// ```
+	pub fn repeat(&self, n: u32) -> Result<Vec<Money>, MoneyErrorOverflow> {
+		let whole = convert_money_to_wide(self);
+		let mut totals = Vec::new();
+		
+		/* no up-front allocation, so a large n that overflows fails before using much memory */
+		for i in 1..=n as i128 {
+			let mut total = convert_wide_to_money(whole * i)?;
+			total.options = self.options;
+			totals.push(total);
+		}
+		
+		Ok(totals)
+	}
+	
+	/// Returns whether two Money instances are identical, including their sign and `options`.
+	///
+	/// Unlike `==`, which compares economic value only, this also distinguishes formatting.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// let mut m2 = m1;
+	/// m2.options().set_symbol('£');
+	///
+	/// assert!(m1 == m2 && !m1.eq_exact(&m2));
+	/// ```
+	pub fn eq_exact(&self, other: &Money) -> bool {
+		self.dollars == other.dollars &&
+		self.cents == other.cents &&
+		self.sign == other.sign &&
+		self.options == other.options
+	}
+	
+	/// Returns the sum of two Money instances, or `None` if an overflow has occurred.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m1 = Money::new(4, 56, MoneySign::Positive).unwrap();
+	/// let m2 = Money::new(u64::MAX, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(m1.checked_add(&m1), Some(Money::new(9, 12, MoneySign::Positive).unwrap()));
+	/// assert_eq!(m1.checked_add(&m2), None);
+	/// ```
+	pub fn checked_add(&self, other: &Money) -> Option<Money> {
+		convert_wide_to_money(convert_money_to_wide(self) + convert_money_to_wide(other)).ok()
+	}
+	
+	/// Multiplies the amount by `factor`, returning `None` if the result overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(4, 56, MoneySign::Negative).unwrap();
+	///
+	/// assert_eq!(m.checked_mul(3), Some(Money::new(13, 68, MoneySign::Negative).unwrap()));
+	/// assert_eq!(Money::max_representable().checked_mul(2), None);
+	/// ```
+	pub fn checked_mul(&self, factor: u64) -> Option<Money> {
+		let wide = convert_money_to_wide(self).checked_mul(factor as i128)?;
+		let mut m = convert_wide_to_money(wide).ok()?;
+		m.options = self.options;
+		
+		Some(m)
+	}
+	
+	/// Multiplies the amount by `factor`, clamping to `max_representable()` or `min_representable()`
+	/// rather than overflowing.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(u64::MAX / 2, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(Money::from_cents(525).saturating_mul(2), Money::from_cents(1050));
+	/// assert_eq!(m.saturating_mul(3), Money::max_representable());
+	/// assert_eq!((-m).saturating_mul(3), Money::min_representable());
+	/// ```
+	pub fn saturating_mul(&self, factor: u64) -> Money {
+		match self.checked_mul(factor) {
+			Some(m) => { m },
+			None => {
+				let mut m = if self.sign == MoneySign::Negative {
+					Money::min_representable()
+				} else {
+					Money::max_representable()
+				};
+				m.options = self.options;
+				m
+			},
+		}
+	}
+	
+	/// Adds the amounts, returning `MoneyError::CurrencyMismatch` if both are tagged with a currency
+	/// and the currencies differ, or `MoneyError::Overflow` if the sum overflows.
+	///
+	/// An amount without a currency may be added to any other. The result has the options of
+	/// whichever operand is tagged with a currency (preferring `self`), or default options if
+	/// neither is.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, Currency, MoneyError};
+	/// let mut usd = Money::from_cents(525);
+	/// let mut eur = Money::from_cents(100);
+	/// usd.options().set_currency(Some(Currency::Usd));
+	/// eur.options().set_currency(Some(Currency::Eur));
+	///
+	/// assert_eq!(usd.try_add(&usd), Ok(Money::from_cents(1050)));
+	/// assert_eq!(usd.try_add(&eur), Err(MoneyError::CurrencyMismatch));
+	/// ```
+	pub fn try_add(&self, other: &Money) -> Result<Money, MoneyError> {
+		let options = self.common_options(other)?;
+		let mut m = self.force_add_ignoring_currency(other)?;
+		m.options = options;
+		
+		Ok(m)
+	}
+	
+	/// Subtracts `other` from the amount, returning `MoneyError::CurrencyMismatch` if both are tagged
+	/// with a currency and the currencies differ, or `MoneyError::Overflow` if the difference
+	/// overflows. The options of the result are chosen as for `try_add()`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, Currency, MoneyError};
+	/// let mut usd = Money::from_cents(525);
+	/// let mut eur = Money::from_cents(100);
+	/// usd.options().set_currency(Some(Currency::Usd));
+	/// eur.options().set_currency(Some(Currency::Eur));
+	///
+	/// assert_eq!(usd.try_sub(&Money::from_cents(25)), Ok(Money::from_cents(500)));
+	/// assert_eq!(usd.try_sub(&eur), Err(MoneyError::CurrencyMismatch));
+	/// ```
+	pub fn try_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+		let options = self.common_options(other)?;
+		let mut m = convert_wide_to_money(convert_money_to_wide(self) - convert_money_to_wide(other))?;
+		m.options = options;
+		
+		Ok(m)
+	}
+	
+	/// Adds the amounts regardless of their currencies, returning an error if the sum overflows.
+	/// The result has default options.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, Currency};
+	/// let mut usd = Money::from_cents(525);
+	/// let mut eur = Money::from_cents(100);
+	/// usd.options().set_currency(Some(Currency::Usd));
+	/// eur.options().set_currency(Some(Currency::Eur));
+	///
+	/// assert_eq!(usd.force_add_ignoring_currency(&eur).unwrap(), Money::from_cents(625));
+	/// ```
+	pub fn force_add_ignoring_currency(&self, other: &Money) -> Result<Money, MoneyErrorOverflow> {
+		convert_wide_to_money(convert_money_to_wide(self) + convert_money_to_wide(other))
+	}
+	
+	/// Multiplies the amount by the ratio `num / den`, rounding the result to the cent with `mode`.
+	///
+	/// The calculation is exact up to the final rounding, avoiding floating-point error.
+	/// The result keeps the options of the Money instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::RoundingMode;
+	/// let m = Money::new(10, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(m.mul_ratio(7, 8, RoundingMode::HalfUp).unwrap().to_string(), "$8.75");
+	/// ```
+	pub fn mul_ratio(&self, num: i64, den: i64, mode: RoundingMode) -> Result<Money, MoneyError> {
+		self.mul_ratio_with(num, den, &mode)
+	}
+	
+	/// Multiplies the amount by `num / den` like `mul_ratio()`, rounding with a custom `rounder`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::{Rounder, RoundingMode};
+	/// let m = Money::new(10, 0, MoneySign::Positive).unwrap();
+	/// let rounder: &dyn Rounder = &RoundingMode::Floor;
+	///
+	/// assert_eq!(m.mul_ratio_with(2, 3, rounder).unwrap().to_string(), "$6.66");
+	/// ```
+	pub fn mul_ratio_with(&self, num: i64, den: i64, rounder: &dyn Rounder) -> Result<Money, MoneyError> {
+		if den == 0 {
+			return Err(MoneyError::DivideByZero);
+		}
+		
+		let scaled = match convert_money_to_wide(self).checked_mul(num as i128) {
+			Some(r) => { r },
+			None => { return Err(MoneyError::Overflow); },
+		};
+		
+		/* keep the divisor positive, as the rounder expects */
+		let (scaled, den) = if den < 0 { (-scaled, -(den as i128)) } else { (scaled, den as i128) };
+		
+		let mut m = convert_wide_to_money(rounder.round(scaled, den))?;
+		m.options = self.options;
+		
+		Ok(m)
+	}
+	
+	/// Returns the amount as a proportion of `whole`, or `None` if `whole` is zero.
+	///
+	/// The result is never NaN or infinite.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let share = Money::new(25, 0, MoneySign::Positive).unwrap();
+	/// let bill = Money::new(100, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(share.ratio_to(&bill), Some(0.25));
+	/// ```
+	pub fn ratio_to(&self, whole: &Money) -> Option<f64> {
+		let w = convert_money_to_wide(whole);
+		
+		if w == 0 {
+			return None;
+		}
+		
+		Some(convert_money_to_wide(self) as f64 / w as f64)
+	}
+	
+	/// Returns the largest value a Money instance can hold.
+	pub fn max_representable() -> Money {
+		Money {
+			dollars: u64::MAX,
+			cents: 99,
+			sign: MoneySign::Positive,
+			options: Options::new(),
+		}
+	}
+	
+	/// Returns the smallest (most negative) value a Money instance can hold.
+	pub fn min_representable() -> Money {
+		-Money::max_representable()
+	}
+	
+	/// Converts a floating-point number of dollars into a Money type, rounding to the cent with `mode`.
+	///
+	/// The value is rounded from its shortest decimal form, so `0.29` is 29 cents under any mode,
+	/// even though the nearest `f64` is slightly less.
+	///
+	/// Returns an error if the value is not finite or is out of range.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::RoundingMode;
+	/// let m = Money::from_f64(5.255, RoundingMode::Truncate).unwrap();
+	///
+	/// assert_eq!(m.to_string(), "$5.25");
+	/// ```
+	pub fn from_f64(value: f64, mode: RoundingMode) -> Result<Money, MoneyError> {
+		if !value.is_finite() {
+			return Err(MoneyError::NotFinite);
+		}
+		
+		/* the shortest digits that round-trip to the value, e.g. "2.9e-1" */
+		let s = format!("{:e}", value);
+		let (mantissa, exponent) = s.split_once('e').unwrap();
+		let mut exponent = exponent.parse::<i32>().unwrap() + STORED_MINOR_DIGITS as i32;
+		
+		let mut digits = String::with_capacity(mantissa.len());
+		
+		for (i, c) in mantissa.chars().enumerate() {
+			if c == '.' {
+				exponent -= (mantissa.len() - i - 1) as i32;
+			} else {
+				digits.push(c);
+			}
+		}
+		
+		/* at most 17 significant digits, so the scaled value is exact */
+		let scaled = digits.parse::<i128>().unwrap();
+		
+		let whole = if scaled == 0 {
+			0
+		} else if exponent >= 0 {
+			10_i128.checked_pow(exponent as u32)
+				.and_then(|p| scaled.checked_mul(p))
+				.ok_or(MoneyError::Overflow)?
+		} else {
+			/* any divisor beyond the digits rounds alike, so cap it to stay in range */
+			mode.divide(scaled, 10_i128.pow(exponent.unsigned_abs().min(20)))
+		};
+		
+		Ok(convert_wide_to_money(whole)?)
+	}
+	
+	/// Converts a floating-point number of dollars into a Money type, rounding to the cent with `mode`.
+	///
+	/// Out-of-range values (including infinities) are clamped to `max_representable()` or
+	/// `min_representable()`, and NaN is converted to zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::RoundingMode;
+	/// let m = Money::from_f64_saturating(1e30, RoundingMode::HalfUp);
+	///
+	/// assert_eq!(m, Money::max_representable());
+	/// ```
+	pub fn from_f64_saturating(value: f64, mode: RoundingMode) -> Money {
+		if value.is_nan() {
+			return Money::default();
+		}
+		
+		match Money::from_f64(value, mode) {
+			Ok(m) => { m },
+			Err(_) if value < 0.0 => { Money::min_representable() },
+			Err(_) => { Money::max_representable() },
+		}
+	}
+	
+	/// Returns whether two amounts differ by no more than the magnitude of `tolerance`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// let m2 = Money::new(5, 26, MoneySign::Positive).unwrap();
+	///
+	/// assert!(m1.approx_eq(&m2, Money::from_cents(1)));
+	/// assert!(!m1.approx_eq(&m2, Money::default()));
+	/// ```
+	pub fn approx_eq(&self, other: &Money, tolerance: Money) -> bool {
+		let difference = (convert_money_to_wide(self) - convert_money_to_wide(other)).unsigned_abs();
+		
+		difference <= convert_money_to_wide(&tolerance).unsigned_abs()
+	}
+	
+	/// Subtracts `other` from the amount, returning `MoneyError::Negative` if the result would be
+	/// negative, or `MoneyError::Overflow` if it doesn't fit.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m1 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	/// let m2 = Money::new(4, 56, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(m1.monus(&m2).unwrap().to_string(), "$7.93");
+	/// assert!(m2.monus(&m1).is_err());
+	/// ```
+	pub fn monus(&self, other: &Money) -> Result<Money, MoneyError> {
+		let difference = convert_money_to_wide(self) - convert_money_to_wide(other);
+		
+		if difference < 0 {
+			return Err(MoneyError::Negative);
+		}
+		
+		Ok(convert_wide_to_money(difference)?)
+	}
+	
+	/// Returns the amount expressed in units of `unit_cents` (e.g. 100000 for thousands of dollars),
+	/// as a number of units rounded to two decimal places with `mode`.
+	///
+	/// Like the other float helpers, the result is never NaN or infinite: `MoneyError::DivideByZero`
+	/// is returned if `unit_cents` is zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign, RoundingMode};
+	/// let m = Money::new(1500, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(m.in_units(100000, RoundingMode::HalfUp), Ok(1.5));
+	/// ```
+	pub fn in_units(&self, unit_cents: i64, mode: RoundingMode) -> Result<f64, MoneyError> {
+		if unit_cents == 0 {
+			return Err(MoneyError::DivideByZero);
+		}
+		
+		/* hundredths of a unit are rounded exactly before converting to a float */
+		let scaled = convert_money_to_wide(self) * 10_i128.pow(STORED_MINOR_DIGITS);
+		
+		Ok(mode.divide(scaled, unit_cents as i128) as f64 / 100.0)
+	}
+	
+	/// Returns the positive and negative versions of the magnitude, as a `(positive, negative)` pair.
+	///
+	/// The options are preserved in both. A zero value has no negative version, so both are zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	/// let (credit, debit) = m.signed_pair();
+	///
+	/// assert_eq!(credit.to_string(), "$5.25");
+	/// assert_eq!(debit.to_string(), "-$5.25");
+	/// ```
+	pub fn signed_pair(&self) -> (Money, Money) {
+		let mut positive = Money::new(self.dollars, self.cents, MoneySign::Positive).unwrap();
+		let mut negative = Money::new(self.dollars, self.cents, MoneySign::Negative).unwrap();
+		
+		positive.options = self.options;
+		negative.options = self.options;
+		
+		(positive, negative)
+	}
+	
+	/// Re-rounds the amount to its scale, carrying any out-of-range cents into the dollars.
+	///
+	/// The scale is the `minor_digits` option if it's set below 2, so a value displayed without
+	/// cents is rounded to whole dollars using `mode`. Returns an error if the result overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::RoundingMode;
+	/// let mut m = Money::new(10, 50, MoneySign::Positive).unwrap();
+	/// m.options().set_minor_digits(Some(0));
+	///
+	/// assert_eq!(m.clamp_to_scale(RoundingMode::HalfEven).unwrap().to_string(), "$10");
+	/// ```
+	pub fn clamp_to_scale(&self, mode: RoundingMode) -> Result<Money, MoneyErrorOverflow> {
+		let mut whole = convert_money_to_wide(self);
+		
+		if let Some(digits) = self.options.minor_digits() {
+			if digits < STORED_MINOR_DIGITS {
+				let step = 10i128.pow(STORED_MINOR_DIGITS - digits);
+				whole = mode.divide(whole, step) * step;
+			}
+		}
+		
+		let mut m = convert_wide_to_money(whole)?;
+		m.options = self.options;
+		
+		Ok(m)
+	}
+	
+	/// Returns the total of `items`, or an error if it overflows.
+	///
+	/// The total is accumulated in a wider integer and converted once at the end, so
+	/// intermediate totals may exceed the representable range as long as the final total doesn't.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let max = Money::max_representable();
+	/// let items = [max, max, -max];
+	///
+	/// assert_eq!(Money::sum_checked(&items).unwrap(), max);
+	/// assert!(Money::sum_checked(&[max, max]).is_err());
+	/// ```
+	pub fn sum_checked(items: &[Money]) -> Result<Money, MoneyErrorOverflow> {
+		let mut total: i128 = 0;
+		
+		for m in items {
+			total = total.checked_add(convert_money_to_wide(m)).ok_or(MoneyErrorOverflow)?;
+		}
+		
+		convert_wide_to_money(total)
+	}
+	
+	/// Returns `true` if the cents are zero, i.e. the amount is a whole number of dollars.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// assert!(Money::new(5, 0, MoneySign::Positive).unwrap().minor_is_zero());
+	/// assert!(!Money::new(5, 20, MoneySign::Positive).unwrap().minor_is_zero());
+	/// ```
+	pub fn minor_is_zero(&self) -> bool {
+		self.cents == 0
+	}
+	
+	/// Returns `true` if the amount has a fractional (cents) part.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// assert!(Money::new(5, 25, MoneySign::Positive).unwrap().has_fraction());
+	/// assert!(!Money::new(5, 0, MoneySign::Positive).unwrap().has_fraction());
+	/// ```
+	pub fn has_fraction(&self) -> bool {
+		!self.minor_is_zero()
+	}
+	
+	/// Returns the number of trailing zeros in the two minor digits, from 0 to 2.
+	///
+	/// An amount with `n` trailing zeros can be held with `2 - n` minor digits without loss.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// assert_eq!(Money::new(5, 0, MoneySign::Positive).unwrap().minor_trailing_zeros(), 2);
+	/// assert_eq!(Money::new(5, 20, MoneySign::Positive).unwrap().minor_trailing_zeros(), 1);
+	/// assert_eq!(Money::new(5, 25, MoneySign::Positive).unwrap().minor_trailing_zeros(), 0);
+	/// ```
+	pub fn minor_trailing_zeros(&self) -> u32 {
+		if self.cents == 0 {
+			STORED_MINOR_DIGITS
+		} else if self.cents.is_multiple_of(10) {
+			1
+		} else {
+			0
+		}
+	}
+	
+	/// Returns the total of an iterator of parse results, stopping at the first parse error.
+	///
+	/// Returns `MoneyError::String` for a parse error, or `MoneyError::Overflow` if the total overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneyError};
+	/// let column = ["$5.25", "-$1.50", "$10.00"];
+	/// let total = Money::try_sum(column.iter().map(|s| Money::from_str(s))).unwrap();
+	///
+	/// assert_eq!(total.to_string(), "$13.75");
+	///
+	/// let column = ["$5.25", "oops", "$10.00"];
+	/// let total = Money::try_sum(column.iter().map(|s| Money::from_str(s)));
+	///
+	/// assert_eq!(total, Err(MoneyError::String));
+	/// ```
+	pub fn try_sum(iter: impl Iterator<Item = Result<Money, MoneyErrorString>>) -> Result<Money, MoneyError> {
+		let mut total: i128 = 0;
+		
+		for m in iter {
+			total = total.checked_add(convert_money_to_wide(&m?)).ok_or(MoneyError::Overflow)?;
+		}
+		
+		Ok(convert_wide_to_money(total)?)
+	}
+	
+	/// Returns the change due when `tendered` is paid against the amount. The options of the amount
+	/// are kept.
+	///
+	/// Like `monus()`, returns `MoneyError::Negative` for an underpayment, or `MoneyError::Overflow`
+	/// if the change doesn't fit.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let price = Money::new(7, 25, MoneySign::Positive).unwrap();
+	/// let tendered = Money::new(10, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(price.change_from(&tendered).unwrap().to_string(), "$2.75");
+	/// assert!(tendered.change_from(&price).is_err());
+	/// ```
+	pub fn change_from(&self, tendered: &Money) -> Result<Money, MoneyError> {
+		let mut m = tendered.monus(self)?;
+		m.options = self.options;
+		
+		Ok(m)
+	}
+	
+	/// Returns the amount in dollars as a floating point number, which may be inexact for large values.
+	///
+	/// The result is never NaN or infinite.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	///
+	/// assert_eq!(m.to_f64(), -5.25);
+	/// ```
+	pub fn to_f64(&self) -> f64 {
+		convert_money_to_wide(self) as f64 / 100.0
+	}
+	
+	/// Returns the amount as a proportion of `other`, or `MoneyError::DivideByZero` if `other` is zero.
+	///
+	/// Like `ratio_to()`, the result is never NaN or infinite.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign, MoneyError};
+	/// let share = Money::new(25, 0, MoneySign::Positive).unwrap();
+	/// let bill = Money::new(100, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(share.try_ratio(&bill), Ok(0.25));
+	/// assert_eq!(share.try_ratio(&Money::default()), Err(MoneyError::DivideByZero));
+	/// ```
+	pub fn try_ratio(&self, other: &Money) -> Result<f64, MoneyError> {
+		self.ratio_to(other).ok_or(MoneyError::DivideByZero)
+	}
+	
+	/// Returns how many whole `unit`s fit in the amount, and the leftover amount.
+	///
+	/// The leftover has the sign of the amount and keeps its options. Returns
+	/// `MoneyError::DivideByZero` if `unit` is zero, `MoneyError::SignMismatch` if the signs differ
+	/// (see `same_sign()`), or `MoneyError::Overflow` if the count doesn't fit in a `u64`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// let (count, leftover) = Money::from_cents(1100).checked_div_money(&Money::from_cents(250)).unwrap();
+	///
+	/// assert_eq!(count, 4);
+	/// assert_eq!(leftover, Money::from_cents(100));
+	/// ```
+	pub fn checked_div_money(&self, unit: &Money) -> Result<(u64, Money), MoneyError> {
+		let whole = convert_money_to_wide(self);
+		let divisor = convert_money_to_wide(unit);
+		
+		if divisor == 0 {
+			return Err(MoneyError::DivideByZero);
+		}
+		
+		if !self.same_sign(unit) {
+			return Err(MoneyError::SignMismatch);
+		}
+		
+		let count = u64::try_from(whole / divisor).map_err(|_| MoneyError::Overflow)?;
+		let mut leftover = convert_wide_to_money(whole % divisor)?;
+		leftover.options = self.options;
+		
+		Ok((count, leftover))
+	}
+	
+	/// Returns the amount interpolated linearly from the Money instance to `to` by the fraction `t`,
+	/// rounded to the cent with `mode`. `t` is clamped to the range 0 to 1, so the result always lies
+	/// between the amounts. Returns `MoneyError::NotFinite` if `t` is NaN.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, RoundingMode};
+	/// let m1 = Money::from_cents(100);
+	/// let m2 = Money::from_cents(201);
+	///
+	/// assert_eq!(m1.lerp(&m2, 0.25, RoundingMode::HalfUp).unwrap(), Money::from_cents(125));
+	/// assert_eq!(m1.lerp(&m2, 2.0, RoundingMode::HalfUp).unwrap(), m2);
+	/// ```
+	pub fn lerp(&self, to: &Money, t: f64, mode: RoundingMode) -> Result<Money, MoneyError> {
+		if t.is_nan() {
+			return Err(MoneyError::NotFinite);
+		}
+		
+		let from = convert_money_to_wide(self);
+		let to = convert_money_to_wide(to);
+		
+		/* the ends are exact, and floating-point error can't carry the result beyond them */
+		let whole = if t <= 0.0 {
+			from
+		} else if t >= 1.0 {
+			to
+		} else {
+			let delta = mode.round_f64((to - from) as f64 * t) as i128;
+			(from + delta).clamp(from.min(to), from.max(to))
+		};
+		
+		Ok(convert_wide_to_money(whole)?)
+	}
+	
+	/// Returns whether the amounts share the same sign. Zero is treated as matching either sign.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// let m = Money::from_cents(525);
+	///
+	/// assert!(m.same_sign(&Money::from_cents(1)));
+	/// assert!(!m.same_sign(&Money::from_cents(-1)));
+	/// assert!(m.same_sign(&Money::default()));
+	/// ```
+	pub fn same_sign(&self, other: &Money) -> bool {
+		let s1 = convert_money_to_wide(self).signum();
+		let s2 = convert_money_to_wide(other).signum();
+		
+		s1 * s2 >= 0
+	}
+	
+	/// Returns the absolute difference between the amounts, in cents. This can't overflow.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// let m2 = Money::new(1, 50, MoneySign::Negative).unwrap();
+	///
+	/// assert_eq!(m1.abs_diff_cents(&m2), 675);
+	/// assert_eq!(m2.abs_diff_cents(&m1), 675);
+	/// ```
+	pub fn abs_diff_cents(&self, other: &Money) -> u128 {
+		convert_money_to_wide(self).abs_diff(convert_money_to_wide(other))
+	}
+	
+	/// Returns the amount halfway between the two amounts, rounded to the cent with `mode`.
+	///
+	/// The midpoint always lies between the amounts, so this can't overflow.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, RoundingMode};
+	/// let m1 = Money::from_cents(100);
+	/// let m2 = Money::from_cents(201);
+	///
+	/// assert_eq!(m1.midpoint(&m2, RoundingMode::HalfUp), Money::from_cents(151));
+	/// assert_eq!(m1.midpoint(&m2, RoundingMode::Truncate), Money::from_cents(150));
+	/// ```
+	pub fn midpoint(&self, other: &Money, mode: RoundingMode) -> Money {
+		let sum = convert_money_to_wide(self) + convert_money_to_wide(other);
+		
+		convert_wide_to_money(mode.divide(sum, 2)).unwrap()
+	}
+	
+	/// Parses each row with `from_str()`, reporting the result of every row independently.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// let results = Money::parse_column(&["$5.25", "five", "(1.50)"]);
+	///
+	/// assert!(results[0].is_ok());
+	/// assert!(results[1].is_err());
+	/// assert_eq!(results[2].as_ref().unwrap().to_string(), "(1.50)");
+	/// ```
+	pub fn parse_column(rows: &[&str]) -> Vec<Result<Money, MoneyErrorString>> {
+		rows.iter().map(|row| Money::from_str(row)).collect()
+	}
+	
+	/// Returns the price point in `ladder` nearest to the amount, or `None` if `ladder` is empty.
+	///
+	/// A tie between two price points returns the lower one. The result keeps the options of the
+	/// Money instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// let ladder = [Money::from_cents(499), Money::from_cents(549), Money::from_cents(599)];
+	///
+	/// assert_eq!(Money::from_cents(540).snap_to_ladder(&ladder), Some(Money::from_cents(549)));
+	/// ```
+	pub fn snap_to_ladder(&self, ladder: &[Money]) -> Option<Money> {
+		let nearest = ladder.iter().min_by_key(|p| (self.abs_diff_cents(p), convert_money_to_wide(p)))?;
+		
+		let mut m = *nearest;
+		m.options = self.options;
+		
+		Some(m)
+	}
+	
+	/// Returns `true` if the amount is a whole multiple of `unit`, such as a coin denomination.
+	///
+	/// Signs are ignored. As with integers, only zero is a multiple of a zero `unit`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(2, 50, MoneySign::Positive).unwrap();
+	///
+	/// assert!(m.is_multiple_of(&Money::from_cents(25)));
+	/// assert!(!m.is_multiple_of(&Money::from_cents(20)));
+	/// ```
+	pub fn is_multiple_of(&self, unit: &Money) -> bool {
+		let (amount, _) = self.to_minor_units();
+		let (unit, _) = unit.to_minor_units();
+		
+		amount.is_multiple_of(unit)
+	}
+	
+	/// Converts free text such as `"5 dollars"` or `"5,25 euros"` into a Money type, leniently
+	/// accepting a trailing currency word (see `Currency::from_word()`).
+	///
+	/// The word sets the currency and symbol of the options. The number may use either '.' or ','
+	/// as the decimal separator, but may not include a symbol. Unknown words return an error.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::from_natural("5,25 euros").unwrap();
+	///
+	/// assert_eq!(m, Money::new(5, 25, MoneySign::Positive).unwrap());
+	/// assert_eq!(m.to_string(), "€5,25");
+	/// ```
+	pub fn from_natural(s: &str) -> Result<Self, MoneyErrorString> {
+		let s = parse::trim_input(s);
+		
+		let (number, word) = match s.rsplit_once(char::is_whitespace) {
+			Some(parts) => { parts },
+			None => { return Err(MoneyErrorString); },
+		};
+		
+		let currency = match Currency::from_word(word) {
+			Some(c) => { c },
+			None => { return Err(MoneyErrorString); },
+		};
+		
+		let opts = ParseOptions::new().accepted_symbols(&[]).allow_entry_suffix(false).allow_codes(false);
+		let mut m = parse::parse_money(number, &opts)?;
+		
+		if currency.minor_digits() == 0 && m.cents != 0 {
+			return Err(MoneyErrorString);
+		}
+		
+		m.options.set_currency(Some(currency));
+		m.options.set_symbol(currency.symbol());
+		m.options.set_show_symbol(true);
+		
+		if currency.minor_digits() != STORED_MINOR_DIGITS {
+			m.options.set_minor_digits(Some(currency.minor_digits()));
+		}
+		
+		Ok(m)
+	}
+	
+	/// Checks the fields of the Money instance, returning every invalid field found.
+	///
+	/// The constructors never produce an invalid value, so this is a diagnostic aid for values
+	/// built directly from fields.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// assert!(Money::from_cents(-525).validate().is_ok());
+	/// ```
+	pub fn validate(&self) -> Result<(), Vec<FieldError>> {
+		let mut errors = Vec::new();
+		
+		if self.cents >= 100 {
+			errors.push(FieldError::CentsOutOfRange(self.cents));
+		}
+		
+		if self.sign == MoneySign::Negative && self.dollars == 0 && self.cents == 0 {
+			errors.push(FieldError::NegativeZero);
+		}
+		
+		if errors.is_empty() {
+			Ok(())
+		} else {
+			Err(errors)
+		}
+	}
+	
+	/// Copies the `options` of `src` to `dest`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::NegativeView;
+	/// let mut m1 = Money::new(59, 99, MoneySign::Negative).unwrap();
+	/// m1.options().set_symbol('#');
+	/// m1.options().set_negative_view(NegativeView::Paren);
+	///
+	/// let mut m2 = Money::new(1098, 54, MoneySign::Negative).unwrap();
+	/// Money::copy_options(&mut m2, &m1);
+	///
+	/// assert_eq!(m2.to_string(), "(#1098.54)");
+	/// ```
+	pub fn copy_options(dest: &mut Money, src: &Money) {
+		dest.options = src.options;
+	}
+	
+	/// Returns a copy of the Money instance with the `options` of `src`, leaving `self` unchanged.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m1 = Money::new(59, 99, MoneySign::Negative).unwrap();
+	/// m1.options().set_symbol('#');
+	///
+	/// let m2 = Money::new(1098, 54, MoneySign::Negative).unwrap();
+	///
+	/// assert_eq!(m2.with_options_from(&m1).to_string(), "-#1098.54");
+	/// assert_eq!(m2.to_string(), "-$1098.54");
+	/// ```
+	pub fn with_options_from(&self, src: &Money) -> Money {
+		let mut m = *self;
+		Money::copy_options(&mut m, src);
+		m
+	}
+}
+
+impl Money {
+	/* returns the options of the operand tagged with a currency, or an error if the currencies differ */
+	fn common_options(&self, other: &Money) -> Result<Options, MoneyError> {
+		match (self.options.currency(), other.options.currency()) {
+			(Some(c1), Some(c2)) if c1 != c2 => { Err(MoneyError::CurrencyMismatch) },
+			(Some(_), _) => { Ok(self.options) },
+			(None, Some(_)) => { Ok(other.options) },
+			(None, None) => { Ok(Options::new()) },
+		}
+	}
+	
+	/* returns the whole and minor digits of the magnitude, honoring the 'minor_digits' option */
+	fn format_parts(&self) -> (String, String) {
+		let options = self.options_immutable();
+		let minor_digits = if options.force_two_decimals() { None } else { options.minor_digits() };
+		
+		match minor_digits {
+			None | Some(2) => {
+				(self.dollars.to_string(), format!["{:02}", self.cents])
+			},
+			Some(digits) if digits > 2 => {
+				(self.dollars.to_string(), format!["{:02}{}", self.cents, "0".repeat(digits as usize - 2)])
+			},
+			Some(digits) => {
+				/* fewer digits than stored, so round the magnitude half away from zero */
+				let unit = 10_i128.pow(2 - digits);
+				let magnitude = convert_money_to_wide(self).abs();
+				let rounded = RoundingMode::HalfUp.divide(magnitude, unit);
+				let scale = 10_i128.pow(digits);
+				
+				let minor = if digits == 0 {
+					String::new()
+				} else {
+					format!["{:0width$}", rounded % scale, width = digits as usize]
+				};
+				
+				((rounded / scale).to_string(), minor)
+			},
+		}
+	}
+}
+
+fn convert_f64_to_money(whole: f64, mode: RoundingMode) -> Result<Money, MoneyError> {
+	if !whole.is_finite() {
+		return Err(MoneyError::NotFinite);
+	}
+	
+	let whole = mode.round_f64(whole);
+	let max = convert_money_to_wide(&Money::max_representable()) as f64;
+	
+	if whole.abs() > max {
+		return Err(MoneyError::Overflow);
+	}
+	
+	Ok(convert_wide_to_money(whole as i128)?)
+}
+
+/* groups the minor digits in threes, counting from the decimal separator */
+fn group_minor_digits(digits: &str, separator: char) -> String {
+	let mut s = String::new();
+	
+	for (i, c) in digits.chars().enumerate() {
+		if i > 0 && i % 3 == 0 {
+			s.push(separator);
+		}
+		s.push(c);
+	}
+	
+	s
+}
+
+fn group_digits(digits: &str, style: GroupingStyle, separator: char) -> String {
+	let mut groups = Vec::new();
+	let mut rest = digits;
+	let mut size = 3;
+	
+	while rest.len() > size {
+		let (head, tail) = rest.split_at(rest.len() - size);
+		groups.push(tail);
+		rest = head;
+		
+		if style == GroupingStyle::Indian {
+			size = 2;
+		}
+	}
+	
+	groups.push(rest);
+	groups.reverse();
+	groups.join(&separator.to_string())
+}
+
+/* converts 'minor' units at the given scale to cents, failing if it's out of range or not a whole number of cents */
+fn convert_minor_to_cents(minor: u128, scale: u32) -> Result<u8, MoneyErrorCents> {
+	let limit = 10u128.checked_pow(scale).ok_or(MoneyErrorCents)?;
+	
+	if minor >= limit {
+		return Err(MoneyErrorCents);
+	}
+	
+	let cents = if scale <= STORED_MINOR_DIGITS {
+		minor * 10u128.pow(STORED_MINOR_DIGITS - scale)
+	} else {
+		let step = 10u128.pow(scale - STORED_MINOR_DIGITS);
+		
+		if !minor.is_multiple_of(step) {
+			return Err(MoneyErrorCents);
+		}
+		minor / step
+	};
+	
+	Ok(cents as u8)
+}
+
+/*
+ * Pads to the width of the format spec, honoring fill and alignment (left by default). Unlike
+ * 'Formatter::pad', the precision is ignored rather than applied by truncating the amount.
+ */
+
+fn pad_formatted(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+	let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+	
+	let (before, after) = match f.align() {
+		Some(fmt::Alignment::Right) => { (padding, 0) },
+		Some(fmt::Alignment::Center) => { (padding / 2, padding - padding / 2) },
+		_ => { (0, padding) },
+	};
+	
+	let fill = f.fill().to_string();
+	
+	write!(f, "{}{}{}", fill.repeat(before), s, fill.repeat(after))
+}
+
+/* maps an ASCII digit to its superscript form */
+fn raise_digit(digit: char) -> char {
+	match digit {
+		'1' => { '\u{00B9}' },
+		'2' => { '\u{00B2}' },
+		'3' => { '\u{00B3}' },
+		'0' | '4'..='9' => { char::from_u32(0x2070 + digit as u32 - '0' as u32).unwrap() },
+		_ => { digit },
+	}
+}
+
+fn convert_money_to_whole(money: &Money) -> Result<i64, MoneyErrorOverflow> {
+	i64::try_from(convert_money_to_wide(money)).map_err(|_| MoneyErrorOverflow)
+}
+
+/* the total number of cents always fits in an i128, so this cannot overflow */
+fn convert_money_to_wide(money: &Money) -> i128 {
+	let whole = money.dollars as i128 * 100 + money.cents as i128;
+	
+	if money.sign == MoneySign::Negative {
+		-whole
+	} else {
+		whole
+	}
+}
+
+fn convert_wide_to_money(whole: i128) -> Result<Money, MoneyErrorOverflow> {
+	let sign = if whole < 0 {
+		MoneySign::Negative
+	} else {
+		MoneySign::Positive
+	};
+	
+	let magnitude = whole.unsigned_abs();
+	
+	match u64::try_from(magnitude / 100) {
+		Ok(dollars) => {
+			Ok(
+				Money {
+					dollars,
+					cents: (magnitude % 100) as u8,
+					sign,
+					options: Options::new(),
+				}
+			)
+		},
+		Err(_) => {
+			Err(MoneyErrorOverflow)
+		},
+	}
+}
+
+fn convert_whole_to_money(whole: i64) -> Money {
+	let sign = if whole < 0 { MoneySign::Negative } else { MoneySign::Positive };
+	
+	/* the magnitude of i64::MIN doesn't fit in an i64 */
+	let magnitude = whole.unsigned_abs();
+	
+	Money {
+		dollars: magnitude / 100,
+		cents: (magnitude % 100) as u8,
+		sign,
+		options: Options::new()
+	}
+}
+
+impl Default for Money {
+    fn default() -> Self {
+		Self {
+			dollars: 0,
+			cents: 0,
+			sign: MoneySign::Positive,
+			options: Options::new(),
+		}
+	}
+}
+
+impl Add for Money {
+	type Output = Self;
+	
+	fn add(self, other: Self) -> Self {
+		let whole_1 = convert_money_to_whole(&self).expect("overflow on addition");
+		let whole_2 = convert_money_to_whole(&other).expect("overflow on addition");
+		
+		match whole_1.checked_add(whole_2) {
+			Some(sum) => {
+				convert_whole_to_money(sum)
+			},
+			None => {
+				panic!("overflow on addition");
+			},
+		}
+	}
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Money {
+	type Output = Self;
+	
+	fn sub(self, other: Self) -> Self {
+		let whole_1 = convert_money_to_whole(&self).expect("overflow on subtraction");
+		let whole_2 = convert_money_to_whole(&other).expect("overflow on subtraction");
+		
+		match whole_1.checked_sub(whole_2) {
+			Some(difference) => {
+				convert_whole_to_money(difference)
+			},
+			None => {
+				panic!("underflow on subtraction");
+			},
+		}
+	}
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Add<i64> for Money {
+	type Output = Self;
+	
+	/// Adds an amount in minor units (cents), keeping the options of the Money instance.
+	/// Panics on overflow, like `+` between Money instances.
+	fn add(self, cents: i64) -> Self {
+		let sum = convert_money_to_wide(&self) + cents as i128;
+		
+		let mut m = convert_wide_to_money(sum).expect("overflow on addition");
+		m.options = self.options;
+		m
+	}
+}
+
+impl AddAssign<i64> for Money {
+	fn add_assign(&mut self, cents: i64) {
+		*self = *self + cents;
+	}
+}
+
+impl Sub<i64> for Money {
+	type Output = Self;
+	
+	/// Subtracts an amount in minor units (cents), keeping the options of the Money instance.
+	/// Panics on underflow, like `-` between Money instances.
+	fn sub(self, cents: i64) -> Self {
+		let difference = convert_money_to_wide(&self) - cents as i128;
+		
+		let mut m = convert_wide_to_money(difference).expect("underflow on subtraction");
+		m.options = self.options;
+		m
+	}
+}
+
+impl SubAssign<i64> for Money {
+	fn sub_assign(&mut self, cents: i64) {
+		*self = *self - cents;
+	}
+}
+
+impl Sum for Money {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Money::default(), |total, m| total + m)
+	}
+}
+
+impl<'a> Sum<&'a Money> for Money {
+	fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Self {
+		iter.fold(Money::default(), |total, m| total + *m)
+	}
+}
+
+/// Collects an iterator of Money instances into their total.
+///
+/// Panics on overflow, like `+`. For a fallible total, use `sum_checked()` instead.
+///
+/// # Example
+///
+/// ```
+/// # use nmoney::{Money, MoneySign};
+/// let v = vec![
+///     Money::new(5, 25, MoneySign::Positive).unwrap(),
+///     Money::new(1, 50, MoneySign::Negative).unwrap(),
+/// ];
+///
+/// let total: Money = v.iter().copied().collect();
+/// assert_eq!(total.to_string(), "$3.75");
+///
+/// let checked = Money::sum_checked(&v);
+/// assert_eq!(checked.unwrap(), total);
+/// ```
+impl FromIterator<Money> for Money {
+	fn from_iter<I: IntoIterator<Item = Money>>(iter: I) -> Self {
+		iter.into_iter().sum()
+	}
+}
+
+impl Neg for Money {
+	type Output = Self;
+	
+	fn neg(self) -> Self {
+		let sign = if self.sign == MoneySign::Positive {
+			MoneySign::Negative
+		} else {
+			MoneySign::Positive
+		};
+		
+		Self {
+			dollars: self.dollars,
+			cents: self.cents,
+			sign,
+			options: self.options,
+		}
+	}
+}
+
+impl PartialEq for Money {
+	fn eq(&self, other: &Self) -> bool {
+		convert_money_to_wide(self) == convert_money_to_wide(other)
+	}
+}
+
+impl Eq for Money {}
+
+/* hashes the total number of cents, so that values which compare equal hash identically */
+impl Hash for Money {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		convert_money_to_wide(self).hash(state);
+	}
+}
+
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+    }
+}
+
+impl Ord for Money {
+	fn cmp(&self, other: &Self) -> Ordering {
+		convert_money_to_wide(self).cmp(&convert_money_to_wide(other))
+	}
+}
+
+/* compares against a total number of cents, widened so that no value can overflow */
+impl PartialEq<i64> for Money {
+	fn eq(&self, other: &i64) -> bool {
+		convert_money_to_wide(self) == *other as i128
+	}
+}
+
+impl PartialOrd<i64> for Money {
+	fn partial_cmp(&self, other: &i64) -> Option<Ordering> {
+		Some(convert_money_to_wide(self).cmp(&(*other as i128)))
+	}
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let options = self.options_immutable();
+		
+		if let Some(zero) = options.zero_display() {
+			if self.dollars == 0 && self.cents == 0 {
+				return pad_formatted(f, zero);
+			}
+		}
+		
+		let (mut whole, mut minor) = self.format_parts();
+		
+		if let Some(style) = options.grouping() {
+			whole = group_digits(&whole, style, options.grouping_separator());
+		}
+		
+		if options.group_minor() {
+			minor = group_minor_digits(&minor, options.minor_grouping_separator());
+		}
+		
+		/* amounts under a dollar may be shown in whole cents, in place of the symbol */
+		let as_minor = options.sub_dollar_as_minor() && self.dollars == 0;
+		
+		let mut s = if as_minor {
+			self.cents.to_string()
+		} else if minor.is_empty() || (options.hide_zero_cents() && !options.force_two_decimals() && minor.chars().all(|c| c == '0')) {
+			whole
+		} else if options.raised_minor() {
+			format!["{}{}", whole, minor.chars().map(raise_digit).collect::<String>()]
+		} else {
+			format!["{}{}{}", whole, options.decimal_separator(), minor]
+		};
+		
+		let is_zero = self.dollars == 0 && self.cents == 0;
+		let is_negative = self.sign() == MoneySign::Negative && !is_zero;
+		
+		/* 'NegativeView::Hide' simply omits the logic to add the negative indicator */
+		let marker = if is_negative && matches!(options.negative_view(), NegativeView::Minus | NegativeView::ParenMinus) {
+			Some('-')
+		} else if !is_negative && !is_zero && options.force_sign() && options.negative_view() == NegativeView::Minus {
+			Some('+')
+		} else {
+			None
+		};
+		
+		let is_spaced = options.symbol_spacing().unwrap_or(options.symbol_position() == SymbolPosition::Suffix);
+		let space = if options.nbsp() { '\u{00A0}' } else { ' ' };
+		
+		/* the sign marker precedes the symbol, in reading order, unless it is set to follow it */
+		let after_symbol = options.negative_sign_position() == NegativeSignPosition::AfterSymbol;
+		
+		match options.symbol_position() {
+			_ if as_minor => {
+				if let Some(m) = marker {
+					s.insert(0, m);
+				}
+				
+				s.push(options.minor_symbol());
+			},
+			SymbolPosition::Prefix => {
+				if let Some(m) = marker.filter(|_| after_symbol) {
+					s.insert(0, m);
+				}
+				
+				if options.show_symbol() {
+					if is_spaced {
+						s.insert(0, space);
+					}
+					s.insert_str(0, options.symbol_str());
+				}
+				
+				if let Some(m) = marker.filter(|_| !after_symbol) {
+					s.insert(0, m);
+				}
+			},
+			SymbolPosition::Suffix => {
+				if let Some(m) = marker.filter(|_| !after_symbol) {
+					s.push(m);
+				}
+				
+				if options.show_symbol() {
+					if is_spaced {
+						s.push(space);
+					}
+					s.push_str(options.symbol_str());
+				}
+				
+				if let Some(m) = marker.filter(|_| after_symbol) {
+					s.push(m);
+				}
+			},
+		}
+		
+		if is_negative && matches!(options.negative_view(), NegativeView::Paren | NegativeView::ParenMinus) {
+			s.insert(0, '(');
+			s.push(')');
+		}
+		
+		if options.rtl() {
+			s.insert(0, RTL_MARK);
+			s.push(RTL_MARK);
+		}
+		
+		pad_formatted(f, &s)
+    }
+}
+
+impl FromStr for Money {
+    type Err = MoneyErrorString;
+	
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match Money::from_str(s) {
+			Ok(r) => {
+				Ok(r)
+			},
+			Err(_) => {
+				Err(MoneyErrorString)
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_comparison, clippy::assertions_on_constants)]
+mod tests {
+    use super::*;
+	use std::collections::BTreeSet;
+	use std::collections::hash_map::DefaultHasher;
+	use locale::Locale;
+	
+	#[test]
+	fn positive_plus_positive() {
+		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		assert_eq!(
+			m1 + m2,
+			Money { dollars: 17, cents: 5, sign: MoneySign::Positive, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn positive_plus_negative() {
+		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Negative).unwrap();
+		
+		assert_eq!(
+			m1 + m2,
+			Money { dollars: 7, cents: 93, sign: MoneySign::Negative, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn negative_plus_positive() {
+		let m1 = Money::new( 4, 56, MoneySign::Negative).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		assert_eq!(
+			m1 + m2,
+			Money { dollars: 7, cents: 93, sign: MoneySign::Positive, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn negative_plus_negative() {
+		let m1 = Money::new( 4, 56, MoneySign::Negative).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Negative).unwrap();
+		
+		assert_eq!(
+			m1 + m2,
+			Money { dollars: 17, cents: 5, sign: MoneySign::Negative, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn positive_minus_positive() {
+		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		assert_eq!(
+			m1 - m2,
+			Money { dollars: 7, cents: 93, sign: MoneySign::Negative, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn positive_minus_negative() {
+		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Negative).unwrap();
+		
+		assert_eq!(
+			m1 - m2,
+			Money { dollars: 17, cents: 5, sign: MoneySign::Positive, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn negative_minus_positive() {
+		let m1 = Money::new( 4, 56, MoneySign::Negative).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		assert_eq!(
+			m1 - m2,
+			Money { dollars: 17, cents: 5, sign: MoneySign::Negative, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn negative_minus_negative() {
+		let m1 = Money::new( 4, 56, MoneySign::Negative).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Negative).unwrap();
+		
+		assert_eq!(
+			m1 - m2,
+			Money { dollars: 7, cents: 93, sign: MoneySign::Positive, options: Options::new() }
+		);
+	}
+
+	#[test]
+	fn checked_add_overflow() {
+		let m1 = Money::new(u64::MAX, 99, MoneySign::Negative).unwrap();
+		let m2 = Money::new(0, 1, MoneySign::Negative).unwrap();
+		
+		assert_eq!(m1.checked_add(&m2), None);
+		assert_eq!(m1.checked_add(&-m2), Some(Money::new(u64::MAX, 98, MoneySign::Negative).unwrap()));
+	}
+	
+	#[test]
+	fn sum() {
+		let v = [
+			Money::new( 4, 56, MoneySign::Positive).unwrap(),
+			Money::new(12, 49, MoneySign::Positive).unwrap(),
+		];
+		
+		assert_eq!(
+			v.iter().sum::<Money>(),
+			Money { dollars: 17, cents: 5, sign: MoneySign::Positive, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn collect_mixed_sign() {
+		let v = vec![
+			Money::new( 4, 56, MoneySign::Positive).unwrap(),
+			Money::new(12, 49, MoneySign::Negative).unwrap(),
+			Money::new( 1,  0, MoneySign::Positive).unwrap(),
+		];
+		
+		let total: Money = v.into_iter().collect();
+		
+		assert_eq!(
+			total,
+			Money { dollars: 6, cents: 93, sign: MoneySign::Negative, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn collect_empty() {
+		let total: Money = Vec::new().into_iter().collect();
+		
+		assert_eq!(total, Money::default());
+	}
+	
+	#[test]
+	fn negate() {
+		let m = Money::new(15, 30, MoneySign::Positive).unwrap();
+		let m2 = -m;
+		
+		assert_eq!(
+			m2,
+			Money { dollars: 15, cents: 30, sign: MoneySign::Negative, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn as_cents() {
+		let m = Money::new(15, 30, MoneySign::Negative).unwrap();
+		
+		assert_eq!(
+			m.as_cents().unwrap(),
+			-1530
+		);
+	}
+	
+	#[test]
+	fn add_assign() {
+		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		let mut m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		m2 += m1;
+		
+		assert_eq!(
+			m2,
+			Money { dollars: 17, cents: 5, sign: MoneySign::Positive, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn sub_assign() {
+		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		let mut m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		m2 -= m1;
+		
+		assert_eq!(
+			m2,
+			Money { dollars: 7, cents: 93, sign: MoneySign::Positive, options: Options::new() }
+		);
+	}
+	
+	#[test]
+	fn less_than() {
+		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		let e1 = m1 < m2;
+		let e2 = m1 > m2;
+		
+		assert!(e1 && !e2);
+	}
+	
+	#[test]
+	fn less_than_or_equal() {
+		let m1 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		let e1 = m1 <= m2;
+		let e2 = m1 >= m2;
+		
+		assert!(e1 && e2);
+	}
+	
+	#[test]
+	fn greater_than() {
+		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		let e1 = m2 > m1;
+		let e2 = m2 < m1;
+		
+		assert!(e1 && !e2);
+	}
+	
+	#[test]
+	fn greater_than_or_equal() {
+		let m1 = Money::new(12, 50, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		let e1 = m1 >= m2;
+		let e2 = m1 <= m2;
+		
+		assert!(e1 && !e2);
+	}
+	
+	#[test]
+	fn equal_to() {
+		let m1 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		
+		assert!(m1 == m2);
+	}
+	
+	#[test]
+	fn equal_to_negative_zero() {
+		let m1 = Money::default();
+		let m2 = -Money::default();
+		
+		assert!(m1 == m2 && m1.cmp(&m2) == Ordering::Equal);
+	}
+	
+	#[test]
+	fn compare_large_values() {
+		let m1 = Money::new(u64::MAX, 99, MoneySign::Positive).unwrap();
+		let m2 = Money::new(u64::MAX, 98, MoneySign::Positive).unwrap();
+		
+		assert!(m1 > m2 && -m1 < -m2);
+	}
+	
+	#[test]
+	fn btree_set_dedup() {
+		let mut set = BTreeSet::new();
+		set.insert(Money::default());
+		set.insert(-Money::default());
+		set.insert(Money::new(5, 25, MoneySign::Negative).unwrap());
+		
+		assert_eq!(set.len(), 2);
+		assert_eq!(set.first(), Some(&Money::new(5, 25, MoneySign::Negative).unwrap()));
+	}
+	
+	fn hash_of(m: &Money) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		m.hash(&mut hasher);
+		hasher.finish()
+	}
+	
+	#[test]
+	fn hash_equal_values() {
+		let mut m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+		let m2 = Money::from_cents(525);
+		m1.options().set_symbol('£');
+		
+		assert_eq!(hash_of(&m1), hash_of(&m2));
+		assert_eq!(hash_of(&Money::default()), hash_of(&-Money::default()));
+	}
+	
+	#[test]
+	fn hash_large_value() {
+		let m1 = Money::new(u64::MAX, 99, MoneySign::Negative).unwrap();
+		let m2 = Money::new(u64::MAX, 99, MoneySign::Negative).unwrap();
+		
+		assert_eq!(hash_of(&m1), hash_of(&m2));
+	}
+	
+	#[test]
+	fn to_string_default() {
+		let m = Money::new(12, 29, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.to_string(), "$12.29");
+	}
+	
+	#[test]
+	fn to_string_new_symbol() {
+		let mut m = Money::new(12, 29, MoneySign::Positive).unwrap();
+		m.options.set_symbol('#');
+		
+		assert_eq!(m.to_string(), "#12.29");
+	}
+	
+	#[test]
+	fn to_string_neg_minus() {
+		let m = Money::new(12, 29, MoneySign::Negative).unwrap();
+		
+		assert_eq!(m.to_string(), "-$12.29");
+	}
+	
+	#[test]
+	fn to_string_neg_paren() {
+		let mut m = Money::new(12, 29, MoneySign::Negative).unwrap();
+		m.options.set_negative_view(NegativeView::Paren);
+		
+		assert_eq!(m.to_string(), "($12.29)");
+	}
+	
+	#[test]
+	fn to_string_neg_hide() {
+		let mut m = Money::new(12, 29, MoneySign::Negative).unwrap();
+		m.options.set_negative_view(NegativeView::Hide);
+		
+		assert_eq!(m.to_string(), "$12.29");
+	}
+	
+	#[test]
+	fn to_string_force_sign_positive() {
+		let mut m = Money::new(12, 29, MoneySign::Positive).unwrap();
+		m.options.set_force_sign(true);
+		
+		assert_eq!(m.to_string(), "+$12.29");
+	}
+	
+	#[test]
+	fn to_string_force_sign_negative() {
+		let mut m = Money::new(12, 29, MoneySign::Negative).unwrap();
+		m.options.set_force_sign(true);
+		
+		assert_eq!(m.to_string(), "-$12.29");
+	}
+	
+	#[test]
+	fn to_string_force_sign_zero() {
+		let mut m = Money::new(0, 0, MoneySign::Positive).unwrap();
+		m.options.set_force_sign(true);
+		
+		assert_eq!(m.to_string(), "$0.00");
+	}
+	
+	#[test]
+	fn to_string_force_sign_paren() {
+		let mut m = Money::new(12, 29, MoneySign::Positive).unwrap();
+		m.options.set_force_sign(true);
+		m.options.set_negative_view(NegativeView::Paren);
+		
+		assert_eq!(m.to_string(), "$12.29");
+	}
+	
+	#[test]
+	fn to_string_neg_minus_sub_dollar() {
+		let m = Money::new(0, 99, MoneySign::Negative).unwrap();
+		
+		assert_eq!(m.to_string(), "-$0.99");
+	}
+	
+	#[test]
+	fn to_string_neg_paren_sub_dollar_no_symbol() {
+		let mut m = Money::new(0, 99, MoneySign::Negative).unwrap();
+		m.options.set_negative_view(NegativeView::Paren);
+		m.options.set_show_symbol(false);
+		
+		assert_eq!(m.to_string(), "(0.99)");
+	}
+	
+	#[test]
+	fn to_string_neg_zero() {
+		let m = -Money::default();
+		
+		assert_eq!(m.to_string(), "$0.00");
+	}
+	
+	#[test]
+	fn to_string_suffix_positive() {
+		let mut m = Money::new(0, 99, MoneySign::Positive).unwrap();
+		m.options.set_symbol('€');
+		m.options.set_symbol_position(SymbolPosition::Suffix);
+		
+		assert_eq!(m.to_string(), "0.99 €");
+	}
+	
+	#[test]
+	fn to_string_suffix_neg_minus() {
+		let mut m = Money::new(0, 99, MoneySign::Negative).unwrap();
+		m.options.set_symbol('€');
+		m.options.set_symbol_position(SymbolPosition::Suffix);
+		
+		assert_eq!(m.to_string(), "0.99- €");
+	}
+	
+	#[test]
+	fn to_string_suffix_neg_paren() {
+		let mut m = Money::new(0, 99, MoneySign::Negative).unwrap();
+		m.options.set_symbol('€');
+		m.options.set_symbol_position(SymbolPosition::Suffix);
+		m.options.set_negative_view(NegativeView::Paren);
+		
+		assert_eq!(m.to_string(), "(0.99 €)");
+	}
+	
+	#[test]
+	fn to_string_suffix_neg_hide() {
+		let mut m = Money::new(0, 99, MoneySign::Negative).unwrap();
+		m.options.set_symbol('€');
+		m.options.set_symbol_position(SymbolPosition::Suffix);
+		m.options.set_negative_view(NegativeView::Hide);
+		
+		assert_eq!(m.to_string(), "0.99 €");
+	}
+	
+	#[test]
+	fn to_string_suffix_force_sign() {
+		let mut m = Money::new(0, 99, MoneySign::Positive).unwrap();
+		m.options.set_symbol('€');
+		m.options.set_symbol_position(SymbolPosition::Suffix);
+		m.options.set_force_sign(true);
+		
+		assert_eq!(m.to_string(), "0.99+ €");
+	}
+	
+	#[test]
+	fn to_string_hide_zero_cents() {
+		let mut m = Money::new(5, 0, MoneySign::Negative).unwrap();
+		m.options.set_hide_zero_cents(true);
+		
+		assert_eq!(m.to_string(), "-$5");
+	}
+	
+	#[test]
+	fn to_string_hide_zero_cents_nonzero() {
+		let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+		m.options.set_hide_zero_cents(true);
+		
+		assert_eq!(m.to_string(), "$5.25");
+	}
+	
+	#[test]
+	fn to_string_minor_digits_pad() {
+		let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+		m.options.set_minor_digits(Some(3));
+		
+		assert_eq!(m.to_string(), "$5.250");
+	}
+	
+	#[test]
+	fn to_string_minor_digits_round() {
+		let mut m = Money::new(9, 95, MoneySign::Negative).unwrap();
+		m.options.set_minor_digits(Some(1));
+		
+		assert_eq!(m.to_string(), "-$10.0");
+		
+		m.options.set_minor_digits(Some(0));
+		
+		assert_eq!(m.to_string(), "-$10");
+	}
+	
+	#[test]
+	fn to_string_minor_digits_hide_zero_cents() {
+		let mut m = Money::new(5, 0, MoneySign::Positive).unwrap();
+		m.options.set_minor_digits(Some(4));
+		m.options.set_hide_zero_cents(true);
+		
+		assert_eq!(m.to_string(), "$5");
+	}
+	
+	#[test]
+	fn to_string_minor_digits_limit() {
+		let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+		
+		assert!(m.options.set_minor_digits(Some(18)));
+		assert_eq!(m.to_string(), "$5.250000000000000000");
+		
+		assert!(!m.options.set_minor_digits(Some(19)));
+		assert!(!m.options.set_minor_digits(Some(u32::MAX)));
+		assert_eq!(m.options.minor_digits(), Some(18));
+	}
+	
+	#[test]
+	fn to_string_grouping_thousands() {
+		let mut m = Money::new(1234567, 89, MoneySign::Negative).unwrap();
+		m.options.set_grouping(Some(GroupingStyle::Thousands));
+		
+		assert_eq!(m.to_string(), "-$1,234,567.89");
+	}
+	
+	#[test]
+	fn to_string_grouping_indian() {
+		let mut m = Money::new(1234567, 0, MoneySign::Positive).unwrap();
+		m.options.set_symbol('₹');
+		m.options.set_grouping(Some(GroupingStyle::Indian));
+		
+		assert_eq!(m.to_string(), "₹12,34,567.00");
+		
+		let mut m = Money::new(123456789, 0, MoneySign::Positive).unwrap();
+		m.options.set_grouping(Some(GroupingStyle::Indian));
+		
+		assert_eq!(m.to_string(), "$12,34,56,789.00");
+	}
+	
+	#[test]
+	fn to_string_grouping_short() {
+		let mut m = Money::new(999, 0, MoneySign::Positive).unwrap();
+		m.options.set_grouping(Some(GroupingStyle::Indian));
+		
+		assert_eq!(m.to_string(), "$999.00");
+		
+		m.options.set_grouping(Some(GroupingStyle::Thousands));
+		
+		assert_eq!(m.to_string(), "$999.00");
+	}
+	
+	#[test]
+	fn to_string_symbol_str() {
+		let mut m = Money::new(12, 29, MoneySign::Negative).unwrap();
+		m.options.set_symbol_str("US$");
+		
+		assert_eq!(m.to_string(), "-US$12.29");
+		assert_eq!(m.options.symbol(), 'U');
+	}
+	
+	#[test]
+	fn to_string_crypto() {
+		let mut m = Money::new(1, 23, MoneySign::Negative).unwrap();
+		m.options = Options::crypto("BTC", 2).unwrap();
+		
+		assert_eq!(m.to_string(), "1.23- BTC");
+		
+		assert!(Money::from_str(&m.to_string()).unwrap().eq_exact(&m));
+		
+		m.options = Options::crypto("BTC", 0).unwrap();
+		
+		assert_eq!(m.to_string(), "1- BTC");
+	}
+	
+	#[test]
+	fn from_str_code_symbol() {
+		let m = Money::from_str("1.23- ETH").unwrap();
+		
+		assert_eq!(m, Money::from_cents(-123));
+		assert_eq!(m.options_immutable().symbol_str(), "ETH");
+		assert_eq!(m.options_immutable().symbol_position(), SymbolPosition::Suffix);
+		assert_eq!(m.to_string(), "1.23- ETH");
+		assert_eq!(Money::from_str("USDT 5").unwrap().to_string(), "USDT 5");
+		
+		assert!(Money::from_str("1.23 Btc").is_err());
+		assert!(Money::from_str("1.23 BITCOIN").is_err());
+		assert!(Money::from_str("1.23BTC").is_err());
+		assert!(Money::from_natural("5 USD dollars").is_err());
+	}
+	
+	#[test]
+	fn crypto_invalid() {
+		assert_eq!(Options::crypto("BTC", 8), Err(MoneyError::Scale));
+		assert_eq!(Options::crypto("", 2), Err(MoneyError::Symbol));
+		assert_eq!(Options::crypto("B TC", 2), Err(MoneyError::Symbol));
+	}
+	
+	#[test]
+	fn to_string_rtl() {
+		let mut m = Money::new(12, 29, MoneySign::Negative).unwrap();
+		m.options.set_symbol('₪');
+		m.options.set_symbol_position(SymbolPosition::Suffix);
+		m.options.set_rtl(true);
+		
+		assert_eq!(m.to_string(), "\u{200F}12.29- ₪\u{200F}");
+	}
+	
+	#[test]
+	fn to_string_rtl_default() {
+		let m = Money::new(12, 29, MoneySign::Negative).unwrap();
+		
+		assert!(!m.to_string().contains(RTL_MARK));
+	}
+	
+	#[test]
+	fn from_str_rtl() {
+		let m = Money::from_str("\u{200F}(12.29 ₪)\u{200F}").unwrap();
+		
+		assert!(m.options_immutable().rtl());
+		assert_eq!(m.to_string(), "\u{200F}(12.29 ₪)\u{200F}");
+	}
+	
+	#[test]
+	fn from_cents() {
+		let m = Money::new(5, 76, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m, Money::from_cents(576));
+	}
+	
+	#[test]
+	fn from_cents_grouped() {
+		let m = Money::from_cents_grouped("1,050,000", ',').unwrap();
+		
+		assert_eq!(m, Money::new(10500, 0, MoneySign::Positive).unwrap());
+	}
+	
+	#[test]
+	fn from_cents_grouped_negative() {
+		let m = Money::from_cents_grouped("-1 050", ' ').unwrap();
+		
+		assert_eq!(m, Money::new(10, 50, MoneySign::Negative).unwrap());
+	}
+	
+	#[test]
+	fn from_cents_grouped_invalid() {
+		assert!(Money::from_cents_grouped("1,05a,000", ',').is_err());
+		assert!(Money::from_cents_grouped(",", ',').is_err());
+	}
+	
+	#[test]
+	fn set_symbol_valid() {
+		let mut m = Money::new(5, 76, MoneySign::Positive).unwrap();
+		
+		assert!(m.options().set_symbol('#'));
+	}
+	
+	#[test]
+	fn set_symbol_str_invalid() {
+		let mut m = Money::new(5, 76, MoneySign::Positive).unwrap();
+		
+		assert!(!m.options().set_symbol_str(""));
+		assert!(!m.options().set_symbol_str("U1"));
+		assert!(!m.options().set_symbol_str("ABCDEFGHIJKLMNOPQ"));
+		assert_eq!(m.options_immutable().symbol_str(), "$");
+	}
+	
+	#[test]
+	fn set_symbol_invalid() {
+		let mut m = Money::new(5, 76, MoneySign::Positive).unwrap();
+		
+		assert!(!m.options().set_symbol('1'));
+	}
+	
+	#[test]
+	fn from_str_pos_no_symbol() {
+		let m1 = Money::new(5, 34, MoneySign::Positive).unwrap();
+		let m2 = Money::from_str("5.34").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().symbol() == '$' &&
+			m2.options_immutable().show_symbol() == false
+		);
+	}
+	
+	#[test]
+	fn from_str_pos_symbol() {
+		let m1 = Money::new(5, 34, MoneySign::Positive).unwrap();
+		let m2 = Money::from_str("$5.34").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().symbol() == '$' &&
+			m2.options_immutable().show_symbol() == true
+		);
+	}
+	
+	#[test]
+	fn from_str_minus_no_symbol() {
+		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
+		let m2 = Money::from_str("-5.34").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().symbol() == '$' &&
+			m2.options_immutable().show_symbol() == false &&
+			m2.options_immutable().negative_view() == NegativeView::Minus
+		);
+	}
+	
+	#[test]
+	fn from_str_minus_symbol() {
+		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
+		let m2 = Money::from_str("-$5.34").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().symbol() == '$' &&
+			m2.options_immutable().show_symbol() == true &&
+			m2.options_immutable().negative_view() == NegativeView::Minus
+		);
+	}
+
+	#[test]
+	fn from_str_paren_no_symbol() {
+		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
+		let m2 = Money::from_str("(5.34)").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().symbol() == '$' &&
+			m2.options_immutable().show_symbol() == false &&
+			m2.options_immutable().negative_view() == NegativeView::Paren
+		);
+	}
+	
+	#[test]
+	fn from_str_paren_symbol() {
+		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
+		let m2 = Money::from_str("($5.34)").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().symbol() == '$' &&
+			m2.options_immutable().show_symbol() == true &&
+			m2.options_immutable().negative_view() == NegativeView::Paren
+		);
+	}
+
+	#[test]
+	fn from_str_sign_symbol_orderings() {
+		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
+		
+		for (s, view) in [
+			("($5.34)", NegativeView::Paren),
+			("$(5.34)", NegativeView::Paren),
+			("-$5.34", NegativeView::Minus),
+			("$-5.34", NegativeView::Minus),
+		] {
+			let m2 = Money::from_str(s).unwrap();
+			
+			assert!(
+				m1 == m2 &&
+				m2.options_immutable().symbol() == '$' &&
+				m2.options_immutable().show_symbol() &&
+				m2.options_immutable().negative_view() == view
+			);
+		}
+	}
+	
+	#[test]
+	fn from_str_invalid_sign_markers() {
+		assert!(Money::from_str("").is_err());
+		assert!(Money::from_str("--5.34").is_err());
+		assert!(Money::from_str("-(5.34)").is_err());
+		assert!(Money::from_str("(+5.34)").is_err());
+		assert!(Money::from_str("$(5.34").is_err());
+		assert!(Money::from_str("$$5.34").is_err());
+	}
+	
+	#[test]
+	fn from_str_invisible_chars() {
+		let m1 = Money::new(5, 34, MoneySign::Positive).unwrap();
+		
+		assert_eq!(Money::from_str("\u{FEFF}$5.34").unwrap(), m1);
+		assert_eq!(Money::from_str(" \u{200B}$5.34\u{200B}\t").unwrap(), m1);
+		assert_eq!(Money::from_str_with_code("\u{FEFF}USD 5.34").unwrap(), m1);
+	}
+	
+	#[test]
+	fn from_str_smart_both_forms() {
+		let m1 = Money::from_str_smart("1.234,56").unwrap();
+		let m2 = Money::from_str_smart("1,234.56").unwrap();
+		
+		assert_eq!(m1, Money::new(1234, 56, MoneySign::Positive).unwrap());
+		assert_eq!(m1, m2);
+		assert_eq!(m1.to_string(), "1.234,56");
+		assert_eq!(m2.to_string(), "1,234.56");
+	}
+	
+	#[test]
+	fn from_str_smart_markers() {
+		let m = Money::from_str_smart("(€1.234.567,8)").unwrap();
+		
+		assert_eq!(m, Money::new(1234567, 80, MoneySign::Negative).unwrap());
+		assert_eq!(m.to_string(), "(€1.234.567,80)");
+	}
+	
+	#[test]
+	fn from_str_smart_indian() {
+		let m = Money::from_str_smart("₹12,34,567.89").unwrap();
+		
+		assert_eq!(m, Money::new(1234567, 89, MoneySign::Positive).unwrap());
+		assert_eq!(m.to_string(), "₹12,34,567.89");
+	}
+	
+	#[test]
+	fn from_str_smart_space_grouping() {
+		let m = Money::from_str_smart("1 234 567,89").unwrap();
+		
+		assert_eq!(m, Money::new(1234567, 89, MoneySign::Positive).unwrap());
+		assert_eq!(m.to_string(), "1 234 567,89");
+		
+		let m = Money::from_str_smart("-1\u{00A0}234,5").unwrap();
+		
+		assert_eq!(m, Money::new(1234, 50, MoneySign::Negative).unwrap());
+		assert!(Money::from_str_smart("1 234.567,89").is_err());
+	}
+	
+	#[test]
+	fn from_str_smart_ambiguous() {
+		/* three trailing digits are always grouping */
+		assert_eq!(Money::from_str_smart("1,234").unwrap(), Money::new(1234, 0, MoneySign::Positive).unwrap());
+		assert_eq!(Money::from_str_smart("1.234").unwrap(), Money::new(1234, 0, MoneySign::Positive).unwrap());
+		assert_eq!(Money::from_str_smart("1,23").unwrap(), Money::new(1, 23, MoneySign::Positive).unwrap());
+		
+		/* from_str() takes a lone '.' as the decimal point, so three decimal places are rejected */
+		assert!(Money::from_str("5.123").is_err());
+		assert!(Money::from_str("1.234").is_err());
+		assert_eq!(Money::from_str("1,234").unwrap(), Money::from_dollars(1234));
+		assert_eq!(Money::from_str("1.234.567").unwrap(), Money::from_dollars(1234567));
+		assert_eq!(Money::from_str_smart("5.123").unwrap(), Money::from_dollars(5123));
+	}
+	
+	#[test]
+	fn from_str_smart_invalid() {
+		assert!(Money::from_str_smart("1.234.56").is_err());
+		assert!(Money::from_str_smart("1,234.5a").is_err());
+		assert!(Money::from_str_smart("$.50").is_err());
+		assert!(Money::from_str_smart("1.2345").is_err());
+		assert!(Money::from_str_smart("1,2,345.00").is_err());
+	}
+	
+	#[test]
+	fn from_str_round_trip_grouped() {
+		let mut m1 = Money::new(1098, 54, MoneySign::Negative).unwrap();
+		m1.options().set_symbol('£');
+		m1.options().set_negative_view(NegativeView::Paren);
+		m1.options().set_grouping(Some(GroupingStyle::Thousands));
+		
+		let m2 = Money::from_str(&m1.to_string()).unwrap();
+		
+		assert!(m1 == m2 && m2.to_string() == "(£1,098.54)");
+	}
+	
+	#[test]
+	fn from_str_round_trip_suffix() {
+		for s in ["0.99- €", "(0.99 €)", "0.99+ €", "0.99-", "+$5", "5,25 €"] {
+			assert_eq!(Money::from_str(s).unwrap().to_string(), s);
+		}
+	}
+	
+	#[test]
+	fn from_str_round_trip_randomized() {
+		/* a simple linear congruential generator keeps the test deterministic without dependencies */
+		let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+		let mut next = move |n: u64| {
+			seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+			(seed >> 33) % n
+		};
+		
+		let symbols = ['$', '£', '€', '¥', '₩', '₹'];
+		let groupings = [None, Some(GroupingStyle::Thousands), Some(GroupingStyle::Indian)];
+		
+		for _ in 0..10000 {
+			let dollars = match next(4) {
+				0 => { 0 },
+				1 => { next(1000) },
+				2 => { next(u32::MAX as u64) * next(u32::MAX as u64) },
+				_ => { u64::MAX - next(1000) },
+			};
+			let cents = if next(3) == 0 { 0 } else { next(100) as u8 };
+			let sign = if next(2) == 0 { MoneySign::Positive } else { MoneySign::Negative };
+			
+			let mut m1 = Money::new(dollars, cents, sign).unwrap();
+			let options = m1.options();
+			
+			options.set_symbol(symbols[next(symbols.len() as u64) as usize]);
+			options.set_show_symbol(next(2) == 0);
+			options.set_negative_view([NegativeView::Minus, NegativeView::Paren, NegativeView::ParenMinus][next(3) as usize]);
+			options.set_force_sign(next(2) == 0);
+			options.set_symbol_position(if next(2) == 0 { SymbolPosition::Prefix } else { SymbolPosition::Suffix });
+			options.set_hide_zero_cents(next(2) == 0);
+			options.set_grouping(groupings[next(3) as usize]);
+			options.set_rtl(next(4) == 0);
+			options.set_symbol_spacing([None, Some(false), Some(true)][next(3) as usize]);
+			options.set_nbsp(next(2) == 0);
+			options.set_negative_sign_position(if next(2) == 0 { NegativeSignPosition::BeforeSymbol } else { NegativeSignPosition::AfterSymbol });
+			
+			let (decimal, grouping) = match next(4) {
+				0 => { ('.', ',') },
+				1 => { (',', '.') },
+				2 => { ('.', ' ') },
+				_ => { (',', '\u{00A0}') },
+			};
+			options.set_decimal_separator(decimal);
+			options.set_grouping_separator(grouping);
+			
+			let s = m1.to_string();
+			let m2 = Money::from_str(&s).unwrap();
+			
+			assert!(m1 == m2 && m2.to_string() == s, "{s} parsed as {m2}");
+		}
+	}
+	
+	#[test]
+	fn from_str_pos_diff_symbol() {
+		let m1 = Money::new(5, 34, MoneySign::Positive).unwrap();
+		let m2 = Money::from_str("£5.34").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().symbol() == '£' &&
+			m2.options_immutable().show_symbol() == true
+		);
+	}
+	
+	#[test]
+	fn from_str_with_code_usd() {
+		let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+		let m2 = Money::from_str_with_code("USD 5.25").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().currency() == Some(Currency::Usd) &&
+			m2.options_immutable().symbol() == '$'
+		);
+	}
+	
+	#[test]
+	fn from_str_with_code_eur_comma() {
+		let m1 = Money::new(5, 25, MoneySign::Negative).unwrap();
+		let m2 = Money::from_str_with_code("EUR -5,25").unwrap();
+		
+		assert!(m1 == m2 && m2.options_immutable().currency() == Some(Currency::Eur));
+		assert_eq!(m2.to_string(), "-€5,25");
+	}
+	
+	#[test]
+	fn from_str_with_code_jpy() {
+		let m1 = Money::new(500, 0, MoneySign::Positive).unwrap();
+		let m2 = Money::from_str_with_code("JPY 500").unwrap();
+		
+		assert!(
+			m1 == m2 &&
+			m2.options_immutable().currency() == Some(Currency::Jpy) &&
+			m2.options_immutable().symbol() == '¥'
+		);
+		assert_eq!(m2.to_string(), "¥500");
+		assert_eq!(m2.to_string(), Money::from_natural("500 yen").unwrap().to_string());
+		assert!(Money::from_str_with_code("JPY 500.50").is_err());
+	}
+	
+	#[test]
+	fn from_str_with_code_invalid() {
+		assert!(Money::from_str_with_code("XYZ 5.25").is_err());
+		assert!(Money::from_str_with_code("USD5.25").is_err());
+		assert!(Money::from_str_with_code("USD 5.2a").is_err());
+	}
+	
+	#[test]
+	fn repeat_zero() {
+		let m = Money::new(9, 99, MoneySign::Positive).unwrap();
+		
+		assert!(m.repeat(0).unwrap().is_empty());
+	}
+	
+	#[test]
+	fn repeat() {
+		let mut m = Money::new(9, 99, MoneySign::Positive).unwrap();
+		m.options().set_symbol('£');
+		
+		let totals: Vec<String> = m.repeat(3).unwrap().iter().map(|t| t.to_string()).collect();
+		
+		assert_eq!(totals, ["£9.99", "£19.98", "£29.97"]);
+	}
+	
+	#[test]
+	fn repeat_overflow() {
+		let m = Money::new(u64::MAX / 2, 0, MoneySign::Negative).unwrap();
+		
+		assert!(m.repeat(3).is_err());
+		assert!(Money::from_cents(i64::MAX).repeat(u32::MAX).is_err());
+	}
+	
+	#[test]
+	fn eq_exact() {
+		let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+		let m2 = Money::new(5, 25, MoneySign::Positive).unwrap();
+		
+		assert!(m1.eq_exact(&m2));
+	}
+	
+	#[test]
+	fn eq_exact_differing_symbols() {
+		let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+		let mut m2 = Money::new(5, 25, MoneySign::Positive).unwrap();
+		m2.options().set_symbol('£');
+		
+		assert!(m1 == m2 && !m1.eq_exact(&m2));
+	}
+	
+	#[test]
+	fn mul_ratio_exact() {
+		let m = Money::new(10, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(
+			m.mul_ratio(7, 8, RoundingMode::Truncate).unwrap(),
+			Money::new(8, 75, MoneySign::Positive).unwrap()
+		);
+	}
+	
+	#[test]
+	fn mul_ratio_thirds() {
+		/* 0.1 * 3 / 3 in floating point is not exactly 0.1 */
+		let m = Money::new(0, 10, MoneySign::Negative).unwrap();
+		
+		assert_eq!(
+			m.mul_ratio(3, 3, RoundingMode::Truncate).unwrap(),
+			Money::new(0, 10, MoneySign::Negative).unwrap()
+		);
+		assert_eq!(
+			m.mul_ratio(1, 3, RoundingMode::HalfUp).unwrap(),
+			Money::new(0, 3, MoneySign::Negative).unwrap()
+		);
+	}
+	
+	#[test]
+	fn mul_ratio_large() {
+		let m = Money::new(u64::MAX, 99, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.mul_ratio(3, 3, RoundingMode::Truncate).unwrap(), m);
+		assert_eq!(m.mul_ratio(2, 1, RoundingMode::Truncate), Err(MoneyError::Overflow));
+	}
+	
+	#[test]
+	fn mul_ratio_zero_denominator() {
+		let m = Money::new(10, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.mul_ratio(7, 0, RoundingMode::HalfUp), Err(MoneyError::DivideByZero));
+	}
+	
+	#[test]
+	fn ratio_to_exact() {
+		let share = Money::new(25, 0, MoneySign::Positive).unwrap();
+		let bill = Money::new(100, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(share.ratio_to(&bill), Some(0.25));
+		assert_eq!((-share).ratio_to(&bill), Some(-0.25));
+	}
+	
+	#[test]
+	fn ratio_to_inexact() {
+		let share = Money::new(1, 0, MoneySign::Positive).unwrap();
+		let bill = Money::new(3, 0, MoneySign::Positive).unwrap();
+		
+		assert!((share.ratio_to(&bill).unwrap() - 1.0 / 3.0).abs() < f64::EPSILON);
+	}
+	
+	#[test]
+	fn ratio_to_zero() {
+		let share = Money::new(25, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(share.ratio_to(&Money::default()), None);
+	}
+	
+	#[test]
+	fn from_f64() {
+		assert_eq!(
+			Money::from_f64(-5.255, RoundingMode::Truncate).unwrap(),
+			Money::new(5, 25, MoneySign::Negative).unwrap()
+		);
+		assert_eq!(
+			Money::from_f64(0.29, RoundingMode::HalfEven).unwrap(),
+			Money::new(0, 29, MoneySign::Positive).unwrap()
+		);
+	}
+	
+	#[test]
+	fn from_f64_shortest_decimal() {
+		for (value, cents) in [(0.29, 29), (0.57, 57), (1.15, 115), (-0.29, -29), (-1.15, -115)] {
+			assert_eq!(Money::from_f64(value, RoundingMode::Truncate).unwrap(), Money::from_cents(cents));
+			assert_eq!(Money::from_f64(value, RoundingMode::Floor).unwrap(), Money::from_cents(cents));
+			assert_eq!(Money::from_f64(value, RoundingMode::Ceiling).unwrap(), Money::from_cents(cents));
+		}
+		
+		assert_eq!(Money::from_f64(1.155, RoundingMode::Floor).unwrap(), Money::from_cents(115));
+		assert_eq!(Money::from_f64(1.155, RoundingMode::Ceiling).unwrap(), Money::from_cents(116));
+		assert_eq!(Money::from_f64(-1.155, RoundingMode::Floor).unwrap(), Money::from_cents(-116));
+		assert_eq!(Money::from_f64(1e-300, RoundingMode::Ceiling).unwrap(), Money::from_cents(1));
+		assert_eq!(Money::from_f64(1e-300, RoundingMode::HalfUp).unwrap(), Money::from_cents(0));
+		assert_eq!(Money::from_f64(1.5e15, RoundingMode::Floor).unwrap(), Money::from_dollars(1_500_000_000_000_000));
+	}
+	
+	#[test]
+	fn from_f64_invalid() {
+		assert_eq!(Money::from_f64(f64::NAN, RoundingMode::HalfUp), Err(MoneyError::NotFinite));
+		assert_eq!(Money::from_f64(f64::INFINITY, RoundingMode::HalfUp), Err(MoneyError::NotFinite));
+		assert_eq!(Money::from_f64(-1e30, RoundingMode::HalfUp), Err(MoneyError::Overflow));
+	}
+	
+	#[test]
+	fn from_f64_saturating() {
+		assert_eq!(Money::from_f64_saturating(1e30, RoundingMode::HalfUp), Money::max_representable());
+		assert_eq!(Money::from_f64_saturating(f64::NEG_INFINITY, RoundingMode::HalfUp), Money::min_representable());
+		assert_eq!(
+			Money::from_f64_saturating(12.5, RoundingMode::HalfUp),
+			Money::new(12, 50, MoneySign::Positive).unwrap()
+		);
+	}
+	
+	#[test]
+	fn from_f64_saturating_nan() {
+		let m = Money::from_f64_saturating(f64::NAN, RoundingMode::HalfUp);
+		
+		assert_eq!(m.to_string(), "$0.00");
+	}
+	
+	#[test]
+	fn approx_eq_one_cent() {
+		let m1 = Money::new(5, 25, MoneySign::Positive).unwrap();
+		let m2 = Money::new(5, 24, MoneySign::Positive).unwrap();
+		
+		assert!(m1.approx_eq(&m2, Money::from_cents(1)));
+		assert!(!m1.approx_eq(&m2, Money::default()));
+	}
+	
+	#[test]
+	fn approx_eq_extremes() {
+		let m1 = Money::max_representable();
+		let m2 = Money::min_representable();
+		
+		assert!(!m1.approx_eq(&m2, Money::max_representable()));
+		assert!(m1.approx_eq(&m1, Money::default()));
+	}
+	
+	#[test]
+	fn monus() {
+		let m1 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		let m2 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m1.monus(&m2).unwrap(), Money::new(7, 93, MoneySign::Positive).unwrap());
+		assert_eq!(m1.monus(&m1).unwrap(), Money::default());
+	}
+	
+	#[test]
+	fn monus_negative() {
+		let m1 = Money::new(12, 49, MoneySign::Positive).unwrap();
+		let m2 = Money::new( 4, 56, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m2.monus(&m1), Err(MoneyError::Negative));
+		assert!(m2.monus(&-m1).is_ok());
+	}
+	
+	#[test]
+	fn monus_overflow() {
+		let max = Money::max_representable();
+		let min = Money::min_representable();
+		
+		assert_eq!(max.monus(&min), Err(MoneyError::Overflow));
+		assert_eq!(max.monus(&Money::from_cents(-1)), Err(MoneyError::Overflow));
+		assert_eq!(max.monus(&max).unwrap(), Money::default());
+	}
+	
+	#[test]
+	fn in_units_thousands() {
+		let m = Money::new(1500, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.in_units(100000, RoundingMode::HalfUp), Ok(1.5));
+		assert_eq!((-m).in_units(100000, RoundingMode::HalfUp), Ok(-1.5));
+		assert_eq!(m.in_units(-100000, RoundingMode::HalfUp), Ok(-1.5));
+	}
+	
+	#[test]
+	fn in_units_rounding() {
+		let m = Money::new(1234, 56, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.in_units(100000, RoundingMode::HalfUp), Ok(1.23));
+		assert_eq!(m.in_units(100000, RoundingMode::Ceiling), Ok(1.24));
+		assert_eq!((-m).in_units(100000, RoundingMode::Floor), Ok(-1.24));
+		assert_eq!(m.in_units(1, RoundingMode::HalfUp), Ok(123456.0));
+	}
+	
+	#[test]
+	fn in_units_zero() {
+		let m = Money::new(1500, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.in_units(0, RoundingMode::HalfUp), Err(MoneyError::DivideByZero));
+	}
+	
+	#[test]
+	fn in_units_extremes() {
+		let min = Money::min_representable();
+		
+		assert!(min.in_units(1, RoundingMode::HalfUp).unwrap().is_finite());
+		assert_eq!(min.in_units(100, RoundingMode::HalfUp), Ok(min.to_f64()));
+	}
+
+	
+	#[test]
+	fn checked_from_parts_scale_0() {
+		let m = Money::checked_from_parts(500, 0, 0, MoneySign::Positive).unwrap();
+		
+		assert!(m.eq_exact(&Money::new(500, 0, MoneySign::Positive).unwrap()));
+		assert!(Money::checked_from_parts(500, 1, 0, MoneySign::Positive).is_err());
+	}
+	
+	#[test]
+	fn checked_from_parts_scale_1() {
+		let m = Money::checked_from_parts(5, 3, 1, MoneySign::Negative).unwrap();
+		
+		assert_eq!(m.to_string(), "-$5.30");
+		assert!(Money::checked_from_parts(5, 10, 1, MoneySign::Negative).is_err());
+	}
+	
+	#[test]
+	fn checked_from_parts_scale_3() {
+		let m = Money::checked_from_parts(5, 990, 3, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.to_string(), "$5.99");
+		assert!(Money::checked_from_parts(5, 999, 3, MoneySign::Positive).is_err());
+		assert!(Money::checked_from_parts(5, 1000, 3, MoneySign::Positive).is_err());
+	}
+	
+	#[test]
+	fn checked_from_parts_scale_too_large() {
+		assert!(Money::checked_from_parts(5, 0, 39, MoneySign::Positive).is_err());
+	}
+	
+	#[test]
+	fn signed_pair() {
+		let mut m = Money::new(12, 34, MoneySign::Positive).unwrap();
+		m.options.set_negative_view(NegativeView::Paren);
+		let (positive, negative) = m.signed_pair();
+		
+		assert_eq!(positive + negative, Money::default());
+		assert_eq!(positive.dollars(), negative.dollars());
+		assert_eq!(positive.cents(), negative.cents());
+		assert_eq!(positive.sign(), MoneySign::Positive);
+		assert_eq!(negative.sign(), MoneySign::Negative);
+		assert_eq!(negative.to_string(), "($12.34)");
+	}
+	
+	#[test]
+	fn signed_pair_zero() {
+		let (positive, negative) = Money::default().signed_pair();
+		
+		assert!(positive.eq_exact(&Money::default()));
+		assert!(negative.eq_exact(&Money::default()));
+	}
+	
+	#[test]
+	fn from_str_accounting_compound_minus() {
+		let m = Money::from_str("-$1,234.56").unwrap();
+		
+		assert_eq!(m, Money::new(1234, 56, MoneySign::Negative).unwrap());
+		assert_eq!(m.options_immutable().negative_view(), NegativeView::Minus);
+		assert_eq!(m.options_immutable().symbol(), '$');
+		assert!(m.options_immutable().show_symbol());
+		assert_eq!(m.options_immutable().grouping(), Some(GroupingStyle::Thousands));
+		assert_eq!(m.options_immutable().grouping_separator(), ',');
+		assert_eq!(m.to_string(), "-$1,234.56");
+	}
+	
+	#[test]
+	fn from_str_accounting_compound_paren() {
+		let m = Money::from_str("($1,234.56)").unwrap();
+		
+		assert_eq!(m, Money::new(1234, 56, MoneySign::Negative).unwrap());
+		assert_eq!(m.options_immutable().negative_view(), NegativeView::Paren);
+		assert_eq!(m.options_immutable().symbol(), '$');
+		assert!(m.options_immutable().show_symbol());
+		assert_eq!(m.options_immutable().grouping(), Some(GroupingStyle::Thousands));
+		assert_eq!(m.options_immutable().grouping_separator(), ',');
+		assert_eq!(m.to_string(), "($1,234.56)");
+	}
+	
+	#[test]
+	fn to_minor_units() {
+		let m = Money::new(5, 25, MoneySign::Negative).unwrap();
+		
+		assert_eq!(m.to_minor_units(), (525, MoneySign::Negative));
+		assert_eq!(Money::default().to_minor_units(), (0, MoneySign::Positive));
+	}
+	
+	#[test]
+	fn to_minor_units_max() {
+		let m = Money::new(u64::MAX, 99, MoneySign::Positive).unwrap();
+		
+		assert!(m.as_cents().is_err());
+		assert_eq!(m.to_minor_units(), (u64::MAX as u128 * 100 + 99, MoneySign::Positive));
+	}
+	
+	#[test]
+	fn from_minor_units_round_trip() {
+		for m1 in [
+			Money::new(u64::MAX, 99, MoneySign::Positive).unwrap(),
+			Money::new(u64::MAX, 0, MoneySign::Negative).unwrap(),
+			Money::new(0, 1, MoneySign::Negative).unwrap(),
+			Money::default(),
+		] {
+			let (magnitude, sign) = m1.to_minor_units();
+			let m2 = Money::from_minor_units(magnitude, sign, 2).unwrap();
+			
+			assert!(m1.eq_exact(&m2));
+		}
+	}
+	
+	#[test]
+	fn from_minor_units_scales() {
+		let m = Money::new(12, 30, MoneySign::Positive).unwrap();
+		
+		assert_eq!(Money::from_minor_units(123, MoneySign::Positive, 1).unwrap(), m);
+		assert_eq!(Money::from_minor_units(12300, MoneySign::Positive, 3).unwrap(), m);
+		assert_eq!(Money::from_minor_units(12, MoneySign::Positive, 0).unwrap(), Money::from_cents(1200));
+	}
+	
+	#[test]
+	fn from_minor_units_invalid() {
+		let max = u64::MAX as u128 * 100 + 99;
+		
+		assert_eq!(Money::from_minor_units(max + 1, MoneySign::Positive, 2), Err(MoneyError::Overflow));
+		assert_eq!(Money::from_minor_units(12345, MoneySign::Positive, 3), Err(MoneyError::Cents));
+		assert_eq!(Money::from_minor_units(1, MoneySign::Positive, 39), Err(MoneyError::Scale));
+	}
+	
+	#[test]
+	fn from_minor_units_saturating() {
+		let max = u64::MAX as u128 * 100 + 99;
+		
+		assert_eq!(Money::from_minor_units_saturating(max, MoneySign::Positive), Money::max_representable());
+		assert_eq!(Money::from_minor_units_saturating(max + 1, MoneySign::Positive), Money::max_representable());
+		assert_eq!(Money::from_minor_units_saturating(max + 1, MoneySign::Negative), Money::min_representable());
+		assert_eq!(Money::from_minor_units_saturating(max - 99, MoneySign::Negative), Money::new(u64::MAX, 0, MoneySign::Negative).unwrap());
+		assert_eq!(Money::from_minor_units_saturating(0, MoneySign::Negative), Money::default());
+	}
+	
+	#[test]
+	fn compare_i64_cents() {
+		let m = Money::from_cents(525);
+		
+		assert!(m > 500);
+		assert!(m >= 525);
+		assert!(m == 525);
+		assert!(m != -525);
+		assert!(m < 526);
+		assert!(-m < 0);
+	}
+	
+	#[test]
+	fn compare_i64_cents_overflow() {
+		let max = Money::new(u64::MAX, 99, MoneySign::Positive).unwrap();
+		let min = Money::new(u64::MAX, 99, MoneySign::Negative).unwrap();
+		
+		assert!(max > i64::MAX);
+		assert!(min < i64::MIN);
+		assert!(max != i64::MAX);
+	}
+	
+	#[test]
+	fn clamp_to_scale_over_range_cents() {
+		/* an invalid value, which can't be built through the public API */
+		let m = Money { dollars: 5, cents: 150, sign: MoneySign::Negative, options: Options::new() };
+		let c = m.clamp_to_scale(RoundingMode::HalfUp).unwrap();
+		
+		assert!(c.eq_exact(&Money::new(6, 50, MoneySign::Negative).unwrap()));
+		assert_eq!(c.to_string(), "-$6.50");
+	}
+	
+	#[test]
+	fn clamp_to_scale_minor_digits() {
+		let mut m = Money::new(10, 50, MoneySign::Negative).unwrap();
+		m.options.set_minor_digits(Some(1));
+		
+		assert_eq!(m.clamp_to_scale(RoundingMode::HalfUp).unwrap(), Money::from_cents(-1050));
+		
+		m.options.set_minor_digits(Some(0));
+		
+		assert_eq!(m.clamp_to_scale(RoundingMode::HalfEven).unwrap(), Money::from_cents(-1000));
+		assert_eq!(m.clamp_to_scale(RoundingMode::Floor).unwrap(), Money::from_cents(-1100));
+		assert_eq!(m.clamp_to_scale(RoundingMode::Floor).unwrap().options_immutable().minor_digits(), Some(0));
+	}
+	
+	#[test]
+	fn clamp_to_scale_overflow() {
+		let mut m = Money::new(u64::MAX, 99, MoneySign::Positive).unwrap();
+		m.options.set_minor_digits(Some(0));
+		
+		assert!(m.clamp_to_scale(RoundingMode::Ceiling).is_err());
+	}
+	
+	#[test]
+	fn to_string_zero_display() {
+		let mut m = Money::default();
+		m.options.set_zero_display(Some("FREE"));
+		
+		assert_eq!(m.to_string(), "FREE");
+		
+		m.options.set_zero_display(None);
+		
+		assert_eq!(m.to_string(), "$0.00");
+	}
+	
+	#[test]
+	fn to_string_zero_display_runtime() {
+		let mut m = Money::default();
+		let label = format!("{} {}", "no", "charge");
+		
+		assert!(m.options.set_zero_display(Some(&label)));
+		drop(label);
+		
+		assert_eq!(m.to_string(), "no charge");
+		assert_eq!(m.options.zero_display(), Some("no charge"));
+		
+		assert!(!m.options.set_zero_display(Some(&"x".repeat(33))));
+		assert!(m.options.set_zero_display(Some(&"x".repeat(32))));
+		assert_eq!(m.to_string(), "x".repeat(32));
+	}
+	
+	#[test]
+	fn to_string_zero_display_non_zero() {
+		let mut m = Money::from_cents(-1);
+		m.options.set_zero_display(Some("—"));
+		
+		assert_eq!(m.to_string(), "-$0.01");
+	}
+	
+	#[test]
+	fn sum_checked_large() {
+		let items: Vec<Money> = (1..=10_000).map(Money::from_cents).collect();
+		
+		assert_eq!(Money::sum_checked(&items).unwrap(), Money::from_cents(50_005_000));
+		assert_eq!(Money::sum_checked(&[]).unwrap(), Money::default());
+	}
+	
+	#[test]
+	fn sum_checked_overflow() {
+		let max = Money::max_representable();
+		let min = Money::min_representable();
+		
+		assert!(Money::sum_checked(&[max, Money::from_cents(1)]).is_err());
+		assert!(Money::sum_checked(&[min, min, min]).is_err());
+		assert_eq!(Money::sum_checked(&[max, max, min]).unwrap(), max);
+	}
+	
+	#[test]
+	fn minor_digit_queries() {
+		for (cents, is_zero, zeros) in [(0, true, 2), (20, false, 1), (25, false, 0), (5, false, 0)] {
+			let m = Money::new(5, cents, MoneySign::Negative).unwrap();
+			
+			assert_eq!(m.minor_is_zero(), is_zero);
+			assert_eq!(m.has_fraction(), !is_zero);
+			assert_eq!(m.minor_trailing_zeros(), zeros);
+		}
+	}
+	
+	#[test]
+	fn from_str_round_trip_apostrophe_grouping() {
+		let m = Money::from_str("1'234'567.89").unwrap();
+		
+		assert_eq!(m, Money::new(1234567, 89, MoneySign::Positive).unwrap());
+		assert_eq!(m.options_immutable().grouping_separator(), '\'');
+		assert_eq!(m.to_string(), "1'234'567.89");
+		assert_eq!(Money::from_str_smart("-1'234,5").unwrap(), Money::from_cents(-123450));
+		assert!(Money::from_str("1'23'4.00").is_err());
+	}
+	
+	#[test]
+	fn try_sum_valid() {
+		let column = ["1,234.56", "(£34.56)", "-0.99", "+$1"];
+		let total = Money::try_sum(column.iter().map(|s| Money::from_str(s))).unwrap();
+		
+		assert_eq!(total, Money::new(1200, 1, MoneySign::Positive).unwrap());
+		assert_eq!(Money::try_sum(std::iter::empty()).unwrap(), Money::default());
+	}
+	
+	#[test]
+	fn try_sum_parse_error() {
+		let mut parsed = 0;
+		let column = ["$1.00", "$2.00", "$x", "$4.00"];
+		let total = Money::try_sum(column.iter().map(|s| {
+			parsed += 1;
+			Money::from_str(s)
+		}));
+		
+		assert_eq!(total, Err(MoneyError::String));
+		assert_eq!(parsed, 3);
+	}
+	
+	#[test]
+	fn try_sum_overflow() {
+		let max = Money::max_representable();
+		
+		assert_eq!(Money::try_sum([Ok(max), Ok(max)].into_iter()), Err(MoneyError::Overflow));
+	}
+	
+	#[test]
+	fn to_string_raised_minor() {
+		let mut m = Money::new(5, 99, MoneySign::Positive).unwrap();
+		m.options.set_raised_minor(true);
+		
+		assert_eq!(m.to_string(), "$5⁹⁹");
+		
+		m.options.set_minor_digits(Some(3));
+		
+		assert_eq!(m.to_string(), "$5⁹⁹⁰");
+	}
+	
+	#[test]
+	fn to_string_raised_minor_all_digits() {
+		let raised: String = "0123456789".chars().map(raise_digit).collect();
+		
+		assert_eq!(raised, "⁰¹²³⁴⁵⁶⁷⁸⁹");
+	}
+	
+	#[test]
+	fn to_string_raised_minor_negative() {
+		let mut m = Money::new(1234, 5, MoneySign::Negative).unwrap();
+		m.options.set_raised_minor(true);
+		m.options.set_negative_view(NegativeView::Paren);
+		m.options.set_grouping(Some(GroupingStyle::Thousands));
+		
+		assert_eq!(m.to_string(), "($1,234⁰⁵)");
+	}
+	
+	#[test]
+	fn change_from_exact() {
+		let price = Money::new(7, 25, MoneySign::Positive).unwrap();
+		
+		assert!(price.change_from(&price).unwrap().eq_exact(&Money::default()));
+	}
+	
+	#[test]
+	fn change_from_overpayment() {
+		let mut price = Money::new(7, 25, MoneySign::Positive).unwrap();
+		price.options.set_symbol('€');
+		let tendered = Money::new(20, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(price.change_from(&tendered).unwrap().to_string(), "€12.75");
+	}
+	
+	#[test]
+	fn change_from_underpayment() {
+		let price = Money::new(7, 25, MoneySign::Positive).unwrap();
+		let tendered = Money::new(7, 24, MoneySign::Positive).unwrap();
 		
-		match whole_1.checked_sub(whole_2) {
-			Some(difference) => {
-				convert_whole_to_money(difference)
-			},
-			None => {
-				panic!("underflow on subtraction");
-			},
+		assert_eq!(price.change_from(&tendered), Err(MoneyError::Negative));
+	}
+	
+	#[test]
+	fn change_from_overflow() {
+		let max = Money::max_representable();
+		let min = Money::min_representable();
+		
+		assert_eq!(min.change_from(&max), Err(MoneyError::Overflow));
+		assert_eq!(Money::from_cents(-1).change_from(&max), Err(MoneyError::Overflow));
+	}
+	
+	#[test]
+	fn to_string_symbol_spacing() {
+		let mut m = Money::new(5, 25, MoneySign::Negative).unwrap();
+		m.options.set_symbol('€');
+		
+		for (position, spacing, s) in [
+			(SymbolPosition::Prefix, Some(false), "-€5.25"),
+			(SymbolPosition::Prefix, Some(true), "-€ 5.25"),
+			(SymbolPosition::Suffix, Some(false), "5.25-€"),
+			(SymbolPosition::Suffix, Some(true), "5.25- €"),
+			(SymbolPosition::Prefix, None, "-€5.25"),
+			(SymbolPosition::Suffix, None, "5.25- €"),
+		] {
+			m.options.set_symbol_position(position);
+			m.options.set_symbol_spacing(spacing);
+			
+			assert_eq!(m.to_string(), s);
 		}
 	}
-}
-
-impl SubAssign for Money {
-    fn sub_assign(&mut self, other: Self) {
-        *self = *self - other;
-    }
-}
-
-impl Neg for Money {
-	type Output = Self;
 	
-	fn neg(self) -> Self {
-		let sign = if self.sign == MoneySign::Positive {
-			MoneySign::Negative
-		} else {
-			MoneySign::Positive
-		};
+	#[test]
+	fn to_string_symbol_spacing_hidden_symbol() {
+		let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+		m.options.set_show_symbol(false);
+		m.options.set_symbol_spacing(Some(true));
 		
-		Self {
-			dollars: self.dollars,
-			cents: self.cents,
-			sign,
-			options: self.options,
+		assert_eq!(m.to_string(), "5.25");
+	}
+	
+	#[test]
+	fn from_str_round_trip_symbol_spacing() {
+		for s in ["$ 5.25", "-$ 5.25", "($ 5.25)", "5.25€", "5.25-€", "(5.25€)"] {
+			assert_eq!(Money::from_str(s).unwrap().to_string(), s);
 		}
+		
+		assert_eq!(Money::from_str("$ -5.25").unwrap().to_string(), "$ -5.25");
+		assert_eq!(Money::from_str("€ 5.25").unwrap().options_immutable().symbol_spacing(), Some(true));
+		assert_eq!(Money::from_str("5.25€").unwrap().options_immutable().symbol_spacing(), Some(false));
+		assert_eq!(Money::from_str("5.25 €").unwrap().options_immutable().symbol_spacing(), None);
 	}
-}
-
-impl PartialEq for Money {
-	fn eq(&self, other: &Self) -> bool {
-		self.dollars == other.dollars &&
-		self.cents == other.cents &&
-		self.sign == other.sign
+	
+	#[test]
+	fn from_str_invalid_symbol_spacing() {
+		assert!(Money::from_str("- 5.25").is_err());
+		assert!(Money::from_str("$  5.25").is_err());
+		assert!(Money::from_str("5.25a").is_err());
+		assert!(Money::from_str("5.25 ").unwrap().to_string() == "5.25");
 	}
-}
-
-impl PartialOrd for Money {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let m1 = self.as_cents().unwrap();
-		let m2 = other.as_cents().unwrap();
+	
+	#[test]
+	fn to_string_paren_minus() {
+		let mut m = Money::new(5, 25, MoneySign::Negative).unwrap();
+		m.options.set_negative_view(NegativeView::ParenMinus);
 		
-		if m1 < m2 {
-			Some(Ordering::Less)
-		} else if m1 > m2 {
-			Some(Ordering::Greater)
-		} else {
-			Some(Ordering::Equal)
-		}
-    }
-}
-
-impl fmt::Display for Money {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let mut s = format!["{}.{:02}", self.dollars, self.cents];
+		assert_eq!(m.to_string(), "(-$5.25)");
 		
-		if self.options_immutable().show_symbol() {
-			s.insert(0, self.options_immutable().symbol());
+		m.options.set_symbol_position(SymbolPosition::Suffix);
+		
+		assert_eq!(m.to_string(), "(5.25- $)");
+	}
+	
+	#[test]
+	fn to_string_paren_minus_positive() {
+		let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+		m.options.set_negative_view(NegativeView::ParenMinus);
+		m.options.set_force_sign(true);
+		
+		assert_eq!(m.to_string(), "$5.25");
+		assert_eq!(Money::default().to_string(), "$0.00");
+	}
+	
+	#[test]
+	fn from_str_paren_minus() {
+		for s in ["(-$5.25)", "($-5.25)", "(-5.25)", "(5.25- €)"] {
+			let m = Money::from_str(s).unwrap();
+			
+			assert_eq!(m, Money::from_cents(-525));
+			assert_eq!(m.options_immutable().negative_view(), NegativeView::ParenMinus);
 		}
 		
-		if self.sign() == MoneySign::Negative {
-			/* 'NegativeView::Hide' simply omits the logic to add the negative indicator */
-			if self.options_immutable().negative_view() == NegativeView::Minus {
-				s.insert(0, '-');
-			} else if self.options_immutable().negative_view() == NegativeView::Paren {
-				s.insert(0, '(');
-				s.push_str(")");
+		assert_eq!(Money::from_str("($-5.25)").unwrap().to_string(), "($-5.25)");
+		assert!(Money::from_str("(+$5.25)").is_err());
+	}
+	
+	#[test]
+	fn from_str_unlisted_symbol() {
+		assert!(Money::from_str("#5.25").is_err());
+		assert!(Money::from_str("5.25 R").is_err());
+		assert!(Money::from_str("(x5.25)").is_err());
+	}
+	
+	#[test]
+	fn from_str_with_symbols() {
+		let accepted = ['#', 'R'];
+		let m = Money::from_str_with_symbols("(#5.25)", &accepted).unwrap();
+		
+		assert_eq!(m, Money::from_cents(-525));
+		assert_eq!(m.to_string(), "(#5.25)");
+		assert_eq!(Money::from_str_with_symbols("5.25 R", &accepted).unwrap().to_string(), "5.25 R");
+		assert_eq!(Money::from_str_with_symbols("5.25", &[]).unwrap(), Money::from_cents(525));
+		assert!(Money::from_str_with_symbols("$5.25", &accepted).is_err());
+		assert!(Money::from_str_with_symbols("$5.25", &[]).is_err());
+	}
+	
+	#[test]
+	fn float_helpers_divide_by_zero() {
+		let m = Money::new(5, 25, MoneySign::Positive).unwrap();
+		let zero = Money::default();
+		
+		assert_eq!(m.percent_change(&zero), None);
+		assert_eq!(m.ratio_to(&zero), None);
+		assert_eq!(m.try_ratio(&zero), Err(MoneyError::DivideByZero));
+		assert_eq!(m.in_units(0, RoundingMode::HalfUp), Err(MoneyError::DivideByZero));
+		assert_eq!(zero.percent_change(&zero), None);
+		assert_eq!(zero.try_ratio(&zero), Err(MoneyError::DivideByZero));
+	}
+	
+	#[test]
+	fn float_helpers_finite() {
+		let max = Money::max_representable();
+		let min = Money::min_representable();
+		let cent = Money::from_cents(1);
+		
+		assert!(max.to_f64().is_finite() && min.to_f64().is_finite());
+		assert!(max.percent_change(&cent).unwrap().is_finite());
+		assert!(min.percent_change(&max).unwrap().is_finite());
+		assert!(max.try_ratio(&cent).unwrap().is_finite());
+	}
+	
+	#[test]
+	fn to_f64() {
+		assert_eq!(Money::from_cents(-525).to_f64(), -5.25);
+		assert_eq!(Money::default().to_f64(), 0.0);
+		assert_eq!(Money::from_cents(1).to_f64(), 0.01);
+	}
+	
+	#[test]
+	fn abs_diff_cents_small() {
+		let m = Money::from_cents(525);
+		
+		assert_eq!(m.abs_diff_cents(&m), 0);
+		assert_eq!(m.abs_diff_cents(&Money::from_cents(524)), 1);
+		assert_eq!(Money::from_cents(-1).abs_diff_cents(&Money::from_cents(1)), 2);
+	}
+	
+	#[test]
+	fn abs_diff_cents_large() {
+		let max = Money::max_representable();
+		let min = Money::min_representable();
+		let span = (u64::MAX as u128 * 100 + 99) * 2;
+		
+		assert_eq!(max.abs_diff_cents(&min), span);
+		assert_eq!(min.abs_diff_cents(&max), span);
+	}
+	
+	#[test]
+	fn reset_options() {
+		let mut m = Money::new(1234, 5, MoneySign::Negative).unwrap();
+		let options = m.options();
+		options.set_symbol_str("CHF");
+		options.set_show_symbol(false);
+		options.set_negative_view(NegativeView::ParenMinus);
+		options.set_symbol_position(SymbolPosition::Suffix);
+		options.set_grouping(Some(GroupingStyle::Indian));
+		options.set_zero_display(Some("—"));
+		options.set_rtl(true);
+		
+		m.reset_options();
+		
+		assert_eq!(*m.options_immutable(), Options::new());
+		assert_eq!(m.options_immutable().symbol_str(), "$");
+		assert!(m.options_immutable().show_symbol());
+		assert_eq!(m.options_immutable().negative_view(), NegativeView::Minus);
+		assert_eq!(m.to_string(), "-$1234.05");
+	}
+	
+	#[test]
+	fn parse_column_mixed() {
+		let rows = ["$1,234.56", "", "-€0.99", "12,345", "(5)", "$$1"];
+		let results = Money::parse_column(&rows);
+		
+		assert_eq!(results.len(), rows.len());
+		assert_eq!(results[0].as_ref().unwrap(), &Money::from_cents(123456));
+		assert!(results[1].is_err());
+		assert_eq!(results[2].as_ref().unwrap(), &Money::from_cents(-99));
+		assert_eq!(results[3].as_ref().unwrap(), &Money::from_cents(1234500));
+		assert_eq!(results[4].as_ref().unwrap(), &Money::from_cents(-500));
+		assert!(results[5].is_err());
+		
+		let failed: Vec<usize> = results.iter().enumerate().filter(|(_, r)| r.is_err()).map(|(i, _)| i).collect();
+		
+		assert_eq!(failed, [1, 5]);
+	}
+	
+	#[test]
+	fn parse_column_empty() {
+		assert!(Money::parse_column(&[]).is_empty());
+	}
+	
+	#[test]
+	fn from_str_credit_debit() {
+		assert_eq!(Money::from_str("5.00 DR").unwrap(), Money::from_cents(-500));
+		assert_eq!(Money::from_str("5.00 CR").unwrap(), Money::from_cents(500));
+		assert_eq!(Money::from_str("$1,234.56 DR").unwrap(), Money::from_cents(-123456));
+		assert_eq!(Money::from_str("5.00 €  DR").unwrap(), Money::from_cents(-500));
+		assert_eq!(Money::from_str("5.00 DR").unwrap().to_string(), "-5.00");
+	}
+	
+	#[test]
+	fn from_str_credit_debit_case() {
+		assert_eq!(Money::from_str("5.00 dr").unwrap(), Money::from_cents(-500));
+		assert_eq!(Money::from_str("5.00 Cr").unwrap(), Money::from_cents(500));
+	}
+	
+	#[test]
+	fn from_str_credit_debit_invalid() {
+		assert!(Money::from_str("5.00DR").is_err());
+		assert!(Money::from_str("-5.00 DR").is_err());
+		assert!(Money::from_str("(5.00) CR").is_err());
+		assert!(Money::from_str("5.00 XR").is_err());
+		assert!(Money::from_str("DR").is_err());
+	}
+	
+	#[test]
+	fn to_string_group_minor() {
+		let mut m = Money::new(1, 23, MoneySign::Negative).unwrap();
+		m.options.set_minor_digits(Some(8));
+		m.options.set_group_minor(true);
+		
+		assert_eq!(m.to_string(), "-$1.230 000 00");
+		
+		m.dollars = 1230;
+		m.options.set_grouping(Some(GroupingStyle::Thousands));
+		m.options.set_minor_grouping_separator('\u{202F}');
+		
+		assert_eq!(m.to_string(), "-$1,230.230\u{202F}000\u{202F}00");
+	}
+	
+	#[test]
+	fn to_string_group_minor_short() {
+		let mut m = Money::new(1, 23, MoneySign::Positive).unwrap();
+		m.options.set_group_minor(true);
+		
+		assert_eq!(m.to_string(), "$1.23");
+		
+		m.options.set_minor_digits(Some(3));
+		
+		assert_eq!(m.to_string(), "$1.230");
+		
+		m.options.set_minor_digits(Some(4));
+		
+		assert_eq!(m.to_string(), "$1.230 0");
+	}
+	
+	#[test]
+	fn snap_to_ladder() {
+		let ladder = [Money::from_cents(499), Money::from_cents(549), Money::from_cents(599)];
+		
+		assert_eq!(Money::from_cents(540).snap_to_ladder(&ladder), Some(Money::from_cents(549)));
+		assert_eq!(Money::from_cents(100).snap_to_ladder(&ladder), Some(Money::from_cents(499)));
+		assert_eq!(Money::from_cents(9999).snap_to_ladder(&ladder), Some(Money::from_cents(599)));
+		assert_eq!(Money::from_cents(574).snap_to_ladder(&ladder), Some(Money::from_cents(549)));
+	}
+	
+	#[test]
+	fn snap_to_ladder_tie() {
+		let ladder = [Money::from_cents(549), Money::from_cents(499)];
+		
+		assert_eq!(Money::from_cents(524).snap_to_ladder(&ladder), Some(Money::from_cents(499)));
+	}
+	
+	#[test]
+	fn snap_to_ladder_extremes() {
+		let mut m = Money::max_representable();
+		m.options.set_symbol('€');
+		let ladder = [Money::min_representable(), Money::from_cents(0)];
+		let snapped = m.snap_to_ladder(&ladder).unwrap();
+		
+		assert_eq!(snapped, Money::default());
+		assert_eq!(snapped.to_string(), "€0.00");
+		assert_eq!(m.snap_to_ladder(&[]), None);
+	}
+	
+	#[test]
+	fn is_multiple_of() {
+		let quarter = Money::from_cents(25);
+		
+		assert!(Money::from_cents(250).is_multiple_of(&quarter));
+		assert!(Money::from_cents(-250).is_multiple_of(&quarter));
+		assert!(Money::from_cents(250).is_multiple_of(&-quarter));
+		assert!(Money::default().is_multiple_of(&quarter));
+		assert!(!Money::from_cents(260).is_multiple_of(&quarter));
+		assert!(!Money::from_cents(1).is_multiple_of(&quarter));
+		assert!(Money::max_representable().is_multiple_of(&Money::from_cents(1)));
+	}
+	
+	#[test]
+	fn is_multiple_of_zero_unit() {
+		assert!(!Money::from_cents(250).is_multiple_of(&Money::default()));
+		assert!(Money::default().is_multiple_of(&Money::default()));
+	}
+	
+	#[test]
+	fn new_carrying() {
+		assert!(Money::new_carrying(5, 150, MoneySign::Positive).unwrap().eq_exact(&Money::new(6, 50, MoneySign::Positive).unwrap()));
+		assert!(Money::new_carrying(5, 99, MoneySign::Negative).unwrap().eq_exact(&Money::new(5, 99, MoneySign::Negative).unwrap()));
+		assert!(Money::new_carrying(0, 12345, MoneySign::Negative).unwrap().eq_exact(&Money::from_cents(-12345)));
+		assert_eq!(Money::new_carrying(0, 0, MoneySign::Negative).unwrap().sign(), MoneySign::Positive);
+	}
+	
+	#[test]
+	fn new_carrying_overflow() {
+		assert!(Money::new_carrying(u64::MAX, 99, MoneySign::Positive).is_ok());
+		assert!(Money::new_carrying(u64::MAX, 100, MoneySign::Positive).is_err());
+		assert!(Money::new_carrying(1, u64::MAX, MoneySign::Positive).is_ok());
+		assert!(Money::new_carrying(u64::MAX - 1, u64::MAX, MoneySign::Positive).is_err());
+	}
+	
+	#[test]
+	fn mul_ratio_with_custom_rounder() {
+		/* always rounds away from zero */
+		struct Up;
+		
+		impl Rounder for Up {
+			fn round(&self, scaled: i128, divisor: i128) -> i128 {
+				let q = scaled / divisor;
+				
+				if scaled % divisor == 0 { q } else { q + scaled.signum() }
 			}
 		}
+		
+		let m = Money::new(10, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.mul_ratio_with(1, 3, &Up).unwrap(), Money::from_cents(334));
+		assert_eq!(m.mul_ratio_with(1, -3, &Up).unwrap(), Money::from_cents(-334));
+		assert_eq!(m.mul_ratio_with(1, 0, &Up), Err(MoneyError::DivideByZero));
+	}
+	
+	#[test]
+	fn from_natural() {
+		let m = Money::from_natural("5 dollars").unwrap();
+		
+		assert_eq!(m, Money::new(5, 0, MoneySign::Positive).unwrap());
+		assert_eq!(m.options_immutable().currency(), Some(Currency::Usd));
+		assert_eq!(m.to_string(), "$5");
+		
+		let m = Money::from_natural("5,25 euros").unwrap();
+		
+		assert_eq!(m, Money::new(5, 25, MoneySign::Positive).unwrap());
+		assert_eq!(m.options_immutable().currency(), Some(Currency::Eur));
+		assert_eq!(m.to_string(), "€5,25");
+	}
+	
+	#[test]
+	fn from_natural_variants() {
+		assert_eq!(Money::from_natural("-1,234.50 Pounds").unwrap(), Money::from_cents(-123450));
+		assert_eq!(Money::from_natural(" 1 dollar ").unwrap(), Money::from_cents(100));
+		assert_eq!(Money::from_natural("500 yen").unwrap().to_string(), "¥500");
+		assert_eq!(Money::from_natural("500.00 yen").unwrap().to_string(), "¥500");
+	}
+	
+	#[test]
+	fn from_natural_invalid() {
+		assert!(Money::from_natural("5 doubloons").is_err());
+		assert!(Money::from_natural("5").is_err());
+		assert!(Money::from_natural("dollars").is_err());
+		assert!(Money::from_natural("$5 dollars").is_err());
+		assert!(Money::from_natural("5.50 yen").is_err());
+	}
+	
+	#[test]
+	fn display_padding() {
+		let m = Money::from_cents(-525);
+		
+		assert_eq!(format!("{:>8}", m), "  -$5.25");
+		assert_eq!(format!("{:<8}|", m), "-$5.25  |");
+		assert_eq!(format!("{}", m), "-$5.25");
+		assert_eq!(format!("{:*^10}", m), "**-$5.25**");
+	}
+	
+	#[test]
+	fn display_precision_ignored() {
+		let mut m = Money::from_cents(525);
+		
+		assert_eq!(format!("{:.2}", m), "$5.25");
+		assert_eq!(format!("{:>8.1}", m), "   $5.25");
+		
+		m.options().set_zero_display(Some("nil"));
+		let zero = Money::default().with_options_from(&m);
+		
+		assert_eq!(format!("{:.1}", zero), "nil");
+		assert_eq!(format!("{:>5}", zero), "  nil");
 
-		write!(f, "{}", s)
-    }
-}
+	}
 
-impl FromStr for Money {
-    type Err = MoneyErrorString;
 	
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		match Money::from_str(s) {
-			Ok(r) => {
-				Ok(r)
-			},
-			Err(_) => {
-				Err(MoneyErrorString)
-			},
-		}
+	#[test]
+	fn checked_mul() {
+		let mut m = Money::new(1_000_000, 1, MoneySign::Negative).unwrap();
+		m.options().set_symbol('€');
+		
+		let r = m.checked_mul(1_000).unwrap();
+		
+		assert_eq!(r, Money::new(1_000_000_010, 0, MoneySign::Negative).unwrap());
+		assert_eq!(r.options_immutable().symbol(), '€');
+		assert_eq!(m.checked_mul(0), Some(Money::from_cents(0)));
+		assert_eq!(m.checked_mul(u64::MAX), None);
+	}
+	
+	#[test]
+	fn saturating_mul() {
+		let m = Money::new(u64::MAX / 1_000, 50, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.saturating_mul(999), Money::new(u64::MAX / 1_000 * 999 + 499, 50, MoneySign::Positive).unwrap());
+		assert_eq!(m.saturating_mul(1_000_000), Money::max_representable());
+		assert_eq!((-m).saturating_mul(u64::MAX), Money::min_representable());
+		assert_eq!(Money::max_representable().saturating_mul(1), Money::max_representable());
+	}
+	
+	#[test]
+	fn from_dollars() {
+		assert_eq!(Money::from_dollars(5).to_string(), "$5.00");
+		assert_eq!(Money::from_dollars(0), Money::from_cents(0));
+		assert_eq!(Money::from_dollars(u64::MAX), Money::new(u64::MAX, 0, MoneySign::Positive).unwrap());
+	}
+	
+	#[test]
+	fn from_dollars_signed() {
+		assert_eq!(Money::from_dollars_signed(-5).to_string(), "-$5.00");
+		assert_eq!(Money::from_dollars_signed(-5), Money::new(5, 0, MoneySign::Negative).unwrap());
+		assert_eq!(Money::from_dollars_signed(5), Money::from_dollars(5));
+		assert_eq!(Money::from_dollars_signed(i64::MIN).dollars(), 1 << 63);
+	}
+	
+	#[test]
+	fn display_sub_dollar_as_minor() {
+		let mut m = Money::new(0, 99, MoneySign::Positive).unwrap();
+		m.options().set_sub_dollar_as_minor(true);
+		
+		assert_eq!(m.to_string(), "99¢");
+		
+		m.options().set_force_sign(true);
+		assert_eq!(m.to_string(), "+99¢");
+		
+		let mut m = Money::new(0, 5, MoneySign::Negative).unwrap();
+		m.options().set_sub_dollar_as_minor(true);
+		
+		assert_eq!(m.to_string(), "-5¢");
+		
+		m.options().set_negative_view(NegativeView::Paren);
+		assert_eq!(m.to_string(), "(5¢)");
+	}
+	
+	#[test]
+	fn display_sub_dollar_as_minor_whole_dollars() {
+		let mut m = Money::new(1, 0, MoneySign::Positive).unwrap();
+		m.options().set_sub_dollar_as_minor(true);
+		
+		assert_eq!(m.to_string(), "$1.00");
+		
+		m = Money::new(12, 34, MoneySign::Negative).unwrap();
+		m.options().set_sub_dollar_as_minor(true);
+		
+		assert_eq!(m.to_string(), "-$12.34");
+	}
+	
+	#[test]
+	fn abs() {
+		let mut m = Money::new(5, 25, MoneySign::Negative).unwrap();
+		m.options().set_symbol('€');
+		
+		assert_eq!(m.abs().to_string(), "€5.25");
+		assert_eq!(Money::from_cents(525).abs(), Money::from_cents(525));
+		assert_eq!(Money::min_representable().abs(), Money::max_representable());
+	}
+	
+	#[test]
+	fn abs_cents_boundaries() {
+		assert_eq!(Money::from_cents(i64::MAX).abs_cents().unwrap(), i64::MAX);
+		assert_eq!(Money::from_cents(-i64::MAX).abs_cents().unwrap(), i64::MAX);
+		assert_eq!(Money::from_cents(0).abs_cents().unwrap(), 0);
+		
+		let min = Money::from_cents(i64::MIN);
+		
+		assert_eq!(min.as_cents().unwrap(), i64::MIN);
+		assert!(min.abs_cents().is_err());
+		assert!(min.abs().as_cents().is_err());
+		assert!(Money::max_representable().abs_cents().is_err());
+	}
+	
+	#[test]
+	fn from_str_symbol_run() {
+		let m = Money::from_str("US$5.25").unwrap();
+		
+		assert_eq!(m, Money::from_cents(525));
+		assert_eq!(m.options_immutable().symbol_str(), "US$");
+		assert_eq!(m.to_string(), "US$5.25");
+		
+		let m = Money::from_str("-R$ 1.234,50").unwrap();
+		
+		assert_eq!(m, Money::from_cents(-123450));
+		assert_eq!(m.to_string(), "-R$ 1.234,50");
+		
+		let m = Money::from_str("5,25 US$").unwrap();
+		
+		assert_eq!(m.options_immutable().symbol_position(), SymbolPosition::Suffix);
+		assert_eq!(m.to_string(), "5,25 US$");
+	}
+	
+	#[test]
+	fn from_str_symbol_combining_mark() {
+		/* a dollar sign with a combining long solidus overlay */
+		let m = Money::from_str("$\u{0338}5.25").unwrap();
+		
+		assert_eq!(m, Money::from_cents(525));
+		assert_eq!(m.options_immutable().symbol_str(), "$\u{0338}");
+		assert_eq!(m.to_string(), "$\u{0338}5.25");
+	}
+	
+	#[test]
+	fn from_str_symbol_run_invalid() {
+		assert!(Money::from_str("US5.25").is_err());
+		assert!(Money::from_str("$€5.25").is_err());
+		assert!(Money::from_str("US $5.25").is_err());
+		assert!(Money::from_str("$ US5.25").is_err());
+		assert!(Money::from_str("5.25US$").is_err());
+		assert!(Money::from_str("U$S$5.25").is_err());
+		assert!(Money::from_str("US$US$US$US$US$5.25").is_err());
 	}
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 	
 	#[test]
-	fn positive_plus_positive() {
-		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn with_options_from() {
+		let mut src = Money::from_cents(100);
+		src.options().set_symbol('€');
+		src.options().set_symbol_position(SymbolPosition::Suffix);
+		src.options().set_decimal_separator(',');
 		
-		assert_eq!(
-			m1 + m2,
-			Money { dollars: 17, cents: 5, sign: MoneySign::Positive, options: Options::new() }
-		);
+		let m = Money::new(1234, 56, MoneySign::Negative).unwrap();
+		let r = m.with_options_from(&src);
+		
+		assert_eq!(r, m);
+		assert_eq!(r.options_immutable(), src.options_immutable());
+		assert_eq!(r.to_string(), "1234,56- €");
+		assert_eq!(m.to_string(), "-$1234.56");
 	}
 	
 	#[test]
-	fn positive_plus_negative() {
-		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Negative).unwrap();
+	fn from_str_trailing_sign_and_symbol() {
+		let m = Money::from_str("5,25- €").unwrap();
 		
-		assert_eq!(
-			m1 + m2,
-			Money { dollars: 7, cents: 93, sign: MoneySign::Negative, options: Options::new() }
-		);
+		assert_eq!(m, Money::from_cents(-525));
+		assert_eq!(m.options_immutable().symbol(), '€');
+		assert_eq!(m.options_immutable().symbol_position(), SymbolPosition::Suffix);
+		assert_eq!(m.options_immutable().decimal_separator(), ',');
+		assert_eq!(m.to_string(), "5,25- €");
+		
+		let m = Money::from_str("1.234,56- €").unwrap();
+		
+		assert_eq!(m, Money::from_cents(-123456));
+		assert_eq!(m.to_string(), "1.234,56- €");
+		assert!(Money::from_str("5,25 -€").is_err());
+		assert!(Money::from_str("-5,25- €").is_err());
 	}
 	
 	#[test]
-	fn negative_plus_positive() {
-		let m1 = Money::new( 4, 56, MoneySign::Negative).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn midpoint_rounding() {
+		let m1 = Money::from_cents(100);
+		let m2 = Money::from_cents(201);
 		
-		assert_eq!(
-			m1 + m2,
-			Money { dollars: 7, cents: 93, sign: MoneySign::Positive, options: Options::new() }
-		);
+		assert_eq!(m1.midpoint(&m2, RoundingMode::HalfUp), Money::from_cents(151));
+		assert_eq!(m1.midpoint(&m2, RoundingMode::Truncate), Money::from_cents(150));
+		assert_eq!(m2.midpoint(&m1, RoundingMode::HalfUp), Money::from_cents(151));
+		assert_eq!(Money::from_cents(-201).midpoint(&Money::from_cents(-100), RoundingMode::HalfUp), Money::from_cents(-150));
+		assert_eq!(Money::from_cents(-100).midpoint(&Money::from_cents(100), RoundingMode::HalfUp), Money::from_cents(0));
 	}
 	
 	#[test]
-	fn negative_plus_negative() {
-		let m1 = Money::new( 4, 56, MoneySign::Negative).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Negative).unwrap();
+	fn midpoint_extremes() {
+		let max = Money::max_representable();
+		let min = Money::min_representable();
 		
-		assert_eq!(
-			m1 + m2,
-			Money { dollars: 17, cents: 5, sign: MoneySign::Negative, options: Options::new() }
-		);
+		assert_eq!(max.midpoint(&max, RoundingMode::HalfUp), max);
+		assert_eq!(min.midpoint(&min, RoundingMode::HalfUp), min);
+		assert_eq!(max.midpoint(&min, RoundingMode::HalfUp), Money::from_cents(0));
 	}
 	
 	#[test]
-	fn positive_minus_positive() {
-		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn try_add_same_currency() {
+		let mut m1 = Money::from_cents(525);
+		let mut m2 = Money::from_cents(-100);
+		m1.options().set_currency(Some(Currency::Eur));
+		m1.options().set_symbol('€');
+		m2.options().set_currency(Some(Currency::Eur));
 		
-		assert_eq!(
-			m1 - m2,
-			Money { dollars: 7, cents: 93, sign: MoneySign::Negative, options: Options::new() }
-		);
+		assert_eq!(m1.try_add(&m2).unwrap().to_string(), "€4.25");
+		assert_eq!(m1.try_sub(&m2).unwrap().to_string(), "€6.25");
+		assert_eq!(m2.try_add(&m1).unwrap().to_string(), "$4.25");
+		
+		/* an amount without a currency is compatible with any currency */
+		let plain = Money::from_cents(75);
+		
+		assert_eq!(plain.try_add(&m1).unwrap().to_string(), "€6.00");
+		assert_eq!(plain.try_sub(&plain).unwrap().options_immutable().currency(), None);
 	}
 	
 	#[test]
-	fn positive_minus_negative() {
-		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Negative).unwrap();
+	fn try_add_currency_mismatch() {
+		let mut m1 = Money::from_cents(525);
+		let mut m2 = Money::from_cents(100);
+		m1.options().set_currency(Some(Currency::Usd));
+		m2.options().set_currency(Some(Currency::Gbp));
 		
-		assert_eq!(
-			m1 - m2,
-			Money { dollars: 17, cents: 5, sign: MoneySign::Positive, options: Options::new() }
-		);
+		assert_eq!(m1.try_add(&m2), Err(MoneyError::CurrencyMismatch));
+		assert_eq!(m1.try_sub(&m2), Err(MoneyError::CurrencyMismatch));
+		assert_eq!(m1.force_add_ignoring_currency(&m2).unwrap(), Money::from_cents(625));
+		assert_eq!(MoneyError::CurrencyMismatch.to_string(), "operands have different currencies");
 	}
 	
 	#[test]
-	fn negative_minus_positive() {
-		let m1 = Money::new( 4, 56, MoneySign::Negative).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn try_add_overflow() {
+		let max = Money::max_representable();
 		
-		assert_eq!(
-			m1 - m2,
-			Money { dollars: 17, cents: 5, sign: MoneySign::Negative, options: Options::new() }
-		);
+		assert_eq!(max.try_add(&Money::from_cents(1)), Err(MoneyError::Overflow));
+		assert_eq!(Money::min_representable().try_sub(&Money::from_cents(1)), Err(MoneyError::Overflow));
+		assert!(max.force_add_ignoring_currency(&max).is_err());
 	}
 	
 	#[test]
-	fn negative_minus_negative() {
-		let m1 = Money::new( 4, 56, MoneySign::Negative).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Negative).unwrap();
+	fn display_force_two_decimals() {
+		let mut m = Money::from_dollars(500);
+		*m.options() = Locale::JaJp.options();
 		
-		assert_eq!(
-			m1 - m2,
-			Money { dollars: 7, cents: 93, sign: MoneySign::Positive, options: Options::new() }
-		);
+		assert_eq!(m.to_string(), "¥500");
+		
+		m.options().set_force_two_decimals(true);
+		assert_eq!(m.to_string(), "¥500.00");
+		
+		m.options().set_hide_zero_cents(true);
+		assert_eq!(m.to_string(), "¥500.00");
+		
+		let mut m = Money::from_cents(123_456);
+		m.options().set_minor_digits(Some(4));
+		m.options().set_force_two_decimals(true);
+		
+		assert_eq!(m.to_string(), "$1234.56");
+		assert_eq!(m.to_compact_string(), "$1.2K");
 	}
-
+	
 	#[test]
-	fn negate() {
-		let m = Money::new(15, 30, MoneySign::Positive).unwrap();
-		let m2 = -m;
+	fn options_snapshot_round_trip_default() {
+		let options = Options::new();
 		
-		assert_eq!(
-			m2,
-			Money { dollars: 15, cents: 30, sign: MoneySign::Negative, options: Options::new() }
-		);
+		assert_eq!(Options::from_snapshot(options.snapshot()).unwrap(), options);
 	}
 	
 	#[test]
-	fn as_cents() {
-		let m = Money::new(15, 30, MoneySign::Negative).unwrap();
+	fn options_snapshot_round_trip_all() {
+		let mut m = Money::from_cents(-123_456_789);
+		let options = m.options();
+		options.set_symbol_str("CHF");
+		options.set_show_symbol(false);
+		options.set_negative_view(NegativeView::ParenMinus);
+		options.set_force_sign(true);
+		options.set_decimal_separator(',');
+		options.set_currency(Some(Currency::Eur));
+		options.set_symbol_position(SymbolPosition::Suffix);
+		options.set_hide_zero_cents(true);
+		options.set_minor_digits(Some(4));
+		options.set_grouping(Some(GroupingStyle::Indian));
+		options.set_grouping_separator('\'');
+		options.set_rtl(true);
+		options.set_zero_display(Some("—"));
+		options.set_raised_minor(true);
+		options.set_symbol_spacing(Some(false));
+		options.set_group_minor(true);
+		options.set_minor_grouping_separator('_');
+		options.set_sub_dollar_as_minor(true);
+		options.set_minor_symbol('p');
+		options.set_force_two_decimals(true);
+		options.set_nbsp(true);
+		options.set_negative_sign_position(NegativeSignPosition::AfterSymbol);
 		
-		assert_eq!(
-			m.as_cents().unwrap(),
-			-1530
-		);
+		let snapshot = m.options_immutable().snapshot();
+		let restored = Options::from_snapshot(snapshot.clone()).unwrap();
+		
+		assert_eq!(restored, *m.options_immutable());
+		assert_eq!(restored.snapshot(), snapshot);
+		
+		let mut r = Money::from_cents(-123_456_789);
+		*r.options() = restored;
+		
+		assert_eq!(r.to_string(), m.to_string());
 	}
 	
 	#[test]
-	fn add_assign() {
-		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
-		let mut m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn options_snapshot_invalid() {
+		let mut snapshot = Options::new().snapshot();
+		snapshot.symbol = String::new();
 		
-		m2 += m1;
+		assert_eq!(Options::from_snapshot(snapshot), Err(MoneyError::Symbol));
 		
-		assert_eq!(
-			m2,
-			Money { dollars: 17, cents: 5, sign: MoneySign::Positive, options: Options::new() }
-		);
+		let mut snapshot = Options::new().snapshot();
+		snapshot.minor_grouping_separator = '3';
+		
+		assert_eq!(Options::from_snapshot(snapshot), Err(MoneyError::Symbol));
+		
+		let mut snapshot = Options::new().snapshot();
+		snapshot.minor_digits = Some(u32::MAX);
+		
+		assert_eq!(Options::from_snapshot(snapshot), Err(MoneyError::Scale));
+		
+		let mut snapshot = Options::new().snapshot();
+		snapshot.zero_display = Some("—".repeat(11));
+		
+		assert_eq!(Options::from_snapshot(snapshot), Err(MoneyError::String));
 	}
 	
 	#[test]
-	fn sub_assign() {
-		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
-		let mut m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn options_snapshot_owned_data() {
+		/* as if read back from storage, with no borrow of the original options */
+		let stored = String::from("nil");
+		let snapshot = options::OptionsSnapshot {
+			symbol: String::from("CHF"),
+			zero_display: Some(stored.clone()),
+			..Options::new().snapshot()
+		};
+		drop(stored);
 		
-		m2 -= m1;
+		let mut m = Money::default();
+		*m.options() = Options::from_snapshot(snapshot.clone()).unwrap();
 		
-		assert_eq!(
-			m2,
-			Money { dollars: 7, cents: 93, sign: MoneySign::Positive, options: Options::new() }
-		);
+		assert_eq!(m.to_string(), "nil");
+		assert_eq!(m.options().snapshot(), snapshot);
 	}
 	
 	#[test]
-	fn less_than() {
-		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn rounding_negative_half() {
+		/* -$2.675, held exactly at three minor digits */
+		let m = fixed::FixedMoney::<3>::from_minor_units(-2675);
 		
-		let e1 = m1 < m2;
-		let e2 = m1 > m2;
+		assert_eq!(m.to_money(RoundingMode::HalfUp).unwrap().to_string(), "-$2.67");
+		assert_eq!(m.to_money(RoundingMode::HalfAwayFromZero).unwrap().to_string(), "-$2.68");
+		assert_eq!((-m).to_money(RoundingMode::HalfUp).unwrap().to_string(), "$2.68");
+		assert_eq!((-m).to_money(RoundingMode::HalfAwayFromZero).unwrap().to_string(), "$2.68");
 		
-		assert!(e1 && !e2);
+		let m = Money::from_cents(-535);
+		
+		assert_eq!(m.mul_ratio(1, 2, RoundingMode::HalfUp).unwrap(), Money::from_cents(-267));
+		assert_eq!(m.mul_ratio(1, 2, RoundingMode::HalfAwayFromZero).unwrap(), Money::from_cents(-268));
 	}
 	
 	#[test]
-	fn less_than_or_equal() {
-		let m1 = Money::new(12, 49, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn from_str_thousands_without_decimals() {
+		let m = Money::from_str("1,000").unwrap();
 		
-		let e1 = m1 <= m2;
-		let e2 = m1 >= m2;
+		assert_eq!(m, Money::from_dollars(1000));
+		assert_eq!(m.options_immutable().grouping(), Some(GroupingStyle::Thousands));
+		assert_eq!(m.options_immutable().decimal_separator(), '.');
+		assert_eq!(m.to_string(), "1,000");
 		
-		assert!(e1 && e2);
+		let m = Money::from_str("10,000").unwrap();
+		
+		assert_eq!(m, Money::from_dollars(10_000));
+		assert_eq!(m.to_string(), "10,000");
+		
+		let mut m = Money::from_str("$10,000").unwrap();
+		m.options().set_hide_zero_cents(false);
+		
+		assert_eq!(m.to_string(), "$10,000.00");
+		assert_eq!(Money::from_str("1,00").unwrap(), Money::from_cents(100));
 	}
 	
 	#[test]
-	fn greater_than() {
-		let m1 = Money::new( 4, 56, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn validate_valid() {
+		assert_eq!(Money::default().validate(), Ok(()));
+		assert_eq!(Money::max_representable().validate(), Ok(()));
+		assert_eq!(Money::min_representable().validate(), Ok(()));
+		assert_eq!(Money::new(0, 0, MoneySign::Negative).unwrap().validate(), Ok(()));
+	}
+	
+	#[test]
+	fn validate_invalid() {
+		let m = Money { dollars: 5, cents: 150, sign: MoneySign::Positive, options: Options::new() };
 		
-		let e1 = m2 > m1;
-		let e2 = m2 < m1;
+		assert_eq!(m.validate(), Err(vec![FieldError::CentsOutOfRange(150)]));
 		
-		assert!(e1 && !e2);
+		let m = Money { dollars: 0, cents: 0, sign: MoneySign::Negative, options: Options::new() };
+		
+		assert_eq!(m.validate(), Err(vec![FieldError::NegativeZero]));
+		assert_eq!(FieldError::NegativeZero.to_string(), "sign field is negative for a zero amount");
+		assert_eq!(FieldError::CentsOutOfRange(150).to_string(), "cents field is out of range: 150");
 	}
 	
 	#[test]
-	fn greater_than_or_equal() {
-		let m1 = Money::new(12, 50, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn display_nbsp() {
+		let mut m = Money::from_cents(-525);
+		m.options().set_symbol_spacing(Some(true));
+		m.options().set_nbsp(true);
 		
-		let e1 = m1 >= m2;
-		let e2 = m1 <= m2;
+		assert_eq!(m.to_string(), "-$\u{00A0}5.25");
+		assert!(m.to_string().contains('\u{00A0}'));
 		
-		assert!(e1 && !e2);
+		m.options().set_symbol_spacing(None);
+		assert_eq!(m.to_string(), "-$5.25");
+		
+		m.options().set_symbol_position(SymbolPosition::Suffix);
+		assert_eq!(m.to_string(), "5.25-\u{00A0}$");
 	}
 	
 	#[test]
-	fn equal_to() {
-		let m1 = Money::new(12, 49, MoneySign::Positive).unwrap();
-		let m2 = Money::new(12, 49, MoneySign::Positive).unwrap();
+	fn from_str_nbsp() {
+		for s in ["$\u{00A0}5.25", "5,25\u{00A0}€", "(1\u{00A0}234.50-\u{00A0}€)"] {
+			let m = Money::from_str(s).unwrap();
+			
+			assert!(m.options_immutable().nbsp());
+			assert_eq!(m.to_string(), s);
+		}
 		
-		assert!(m1 == m2);
+		assert!(!Money::from_str("1\u{00A0}234.50 €").unwrap().options_immutable().nbsp());
 	}
 	
 	#[test]
-	fn to_string_default() {
-		let m = Money::new(12, 29, MoneySign::Positive).unwrap();
+	fn same_sign() {
+		let pos = Money::from_cents(525);
+		let neg = Money::from_cents(-1);
+		let zero = Money::default();
 		
-		assert_eq!(m.to_string(), "$12.29");
+		assert!(pos.same_sign(&Money::max_representable()));
+		assert!(neg.same_sign(&Money::min_representable()));
+		assert!(!pos.same_sign(&neg));
+		assert!(!neg.same_sign(&pos));
+		assert!(zero.same_sign(&pos) && zero.same_sign(&neg) && zero.same_sign(&zero));
+		assert!(neg.same_sign(&Money::new(0, 0, MoneySign::Negative).unwrap()));
 	}
 	
 	#[test]
-	fn to_string_new_symbol() {
-		let mut m = Money::new(12, 29, MoneySign::Positive).unwrap();
-		m.options.set_symbol('#');
-		
-		assert_eq!(m.to_string(), "#12.29");
+	fn as_dollars_u32_boundaries() {
+		assert_eq!(Money::new(u32::MAX as u64, 99, MoneySign::Positive).unwrap().as_dollars_u32().unwrap(), u32::MAX);
+		assert_eq!(Money::new(u32::MAX as u64 + 1, 0, MoneySign::Positive).unwrap().as_dollars_u32(), Err(MoneyError::Overflow));
+		assert_eq!(Money::default().as_dollars_u32().unwrap(), 0);
+		assert_eq!(Money::from_cents(99).as_dollars_u32().unwrap(), 0);
+		assert_eq!(Money::from_cents(-1).as_dollars_u32(), Err(MoneyError::Negative));
+		assert_eq!(Money::min_representable().as_dollars_u32(), Err(MoneyError::Negative));
+
 	}
 	
 	#[test]
-	fn to_string_neg_minus() {
-		let m = Money::new(12, 29, MoneySign::Negative).unwrap();
-		
-		assert_eq!(m.to_string(), "-$12.29");
+	fn as_dollars_i32_boundaries() {
+		assert_eq!(Money::new(i32::MAX as u64, 99, MoneySign::Positive).unwrap().as_dollars_i32().unwrap(), i32::MAX);
+		assert!(Money::new(i32::MAX as u64 + 1, 0, MoneySign::Positive).unwrap().as_dollars_i32().is_err());
+		assert_eq!(Money::new(1 << 31, 99, MoneySign::Negative).unwrap().as_dollars_i32().unwrap(), i32::MIN);
+		assert!(Money::new((1 << 31) + 1, 0, MoneySign::Negative).unwrap().as_dollars_i32().is_err());
+		assert_eq!(Money::from_cents(-99).as_dollars_i32().unwrap(), 0);
 	}
 	
 	#[test]
-	fn to_string_neg_paren() {
-		let mut m = Money::new(12, 29, MoneySign::Negative).unwrap();
-		m.options.set_negative_view(NegativeView::Paren);
-		
-		assert_eq!(m.to_string(), "($12.29)");
+	fn as_minor_i8() {
+		assert_eq!(Money::from_cents(-99).as_minor_i8(), -99);
+		assert_eq!(Money::from_cents(199).as_minor_i8(), 99);
+		assert_eq!(Money::min_representable().as_minor_i8(), -99);
+		assert_eq!(Money::default().as_minor_i8(), 0);
 	}
 	
 	#[test]
-	fn to_string_neg_hide() {
-		let mut m = Money::new(12, 29, MoneySign::Negative).unwrap();
-		m.options.set_negative_view(NegativeView::Hide);
+	fn checked_div_money_exact() {
+		let (count, leftover) = Money::from_cents(1000).checked_div_money(&Money::from_cents(250)).unwrap();
 		
-		assert_eq!(m.to_string(), "$12.29");
+		assert_eq!(count, 4);
+		assert_eq!(leftover, Money::default());
+		
+		let (count, leftover) = Money::from_cents(-1000).checked_div_money(&Money::from_cents(-250)).unwrap();
+		
+		assert_eq!(count, 4);
+		assert_eq!(leftover, Money::default());
+		assert_eq!(Money::default().checked_div_money(&Money::from_cents(-250)).unwrap().0, 0);
 	}
 	
 	#[test]
-	fn from_cents() {
-		let m = Money::new(5, 76, MoneySign::Positive).unwrap();
+	fn checked_div_money_remainder() {
+		let mut m = Money::from_cents(-1099);
+		m.options().set_symbol('€');
 		
-		assert_eq!(m, Money::from_cents(576));
+		let (count, leftover) = m.checked_div_money(&Money::from_cents(-250)).unwrap();
+		
+		assert_eq!(count, 4);
+		assert_eq!(leftover.to_string(), "-€0.99");
+		assert_eq!(Money::from_cents(249).checked_div_money(&Money::from_cents(250)).unwrap(), (0, Money::from_cents(249)));
 	}
 	
 	#[test]
-	fn set_symbol_valid() {
-		let mut m = Money::new(5, 76, MoneySign::Positive).unwrap();
+	fn checked_div_money_errors() {
+		let m = Money::from_cents(1000);
 		
-		assert!(m.options().set_symbol('#'));
+		assert_eq!(m.checked_div_money(&Money::default()), Err(MoneyError::DivideByZero));
+		assert_eq!(m.checked_div_money(&Money::from_cents(-250)), Err(MoneyError::SignMismatch));
+		assert_eq!(Money::max_representable().checked_div_money(&Money::from_cents(1)), Err(MoneyError::Overflow));
+		assert_eq!(MoneyError::SignMismatch.to_string(), "operands have different signs");
 	}
 	
 	#[test]
-	fn set_symbol_invalid() {
-		let mut m = Money::new(5, 76, MoneySign::Positive).unwrap();
+	fn display_negative_sign_position() {
+		let mut m = Money::from_cents(-525);
 		
-		assert!(!m.options().set_symbol('1'));
+		assert_eq!(m.to_string(), "-$5.25");
+		
+		m.options().set_negative_sign_position(NegativeSignPosition::AfterSymbol);
+		assert_eq!(m.to_string(), "$-5.25");
+		
+		m.options().set_symbol_spacing(Some(true));
+		assert_eq!(m.to_string(), "$ -5.25");
+		
+		m.options().set_symbol_position(SymbolPosition::Suffix);
+		assert_eq!(m.to_string(), "5.25 $-");
+		
+		m.options().set_negative_view(NegativeView::ParenMinus);
+		assert_eq!(m.to_string(), "(5.25 $-)");
+		
+		let mut m = Money::from_cents(525);
+		m.options().set_negative_sign_position(NegativeSignPosition::AfterSymbol);
+		m.options().set_force_sign(true);
+		
+		assert_eq!(m.to_string(), "$+5.25");
 	}
 	
 	#[test]
-	fn from_str_pos_no_symbol() {
-		let m1 = Money::new(5, 34, MoneySign::Positive).unwrap();
-		let m2 = Money::from_str("5.34").unwrap();
+	fn from_str_negative_sign_position() {
+		for s in ["$-5.25", "$ -5.25", "5,25 €-", "($-5.25)", "US$+1,234.50", "5.25-"] {
+			let m = Money::from_str(s).unwrap();
+			
+			assert_eq!(m.to_string(), s);
+		}
 		
-		assert!(
-			m1 == m2 &&
-			m2.options_immutable().symbol() == '$' &&
-			m2.options_immutable().show_symbol() == false
-		);
+		assert_eq!(Money::from_str("$-5.25").unwrap().options_immutable().negative_sign_position(), NegativeSignPosition::AfterSymbol);
+		assert_eq!(Money::from_str("-$5.25").unwrap().options_immutable().negative_sign_position(), NegativeSignPosition::BeforeSymbol);
+		assert!(Money::from_str("5,25 € -").is_err());
+		assert!(Money::from_str("5,25-€-").is_err());
 	}
 	
 	#[test]
-	fn from_str_pos_symbol() {
-		let m1 = Money::new(5, 34, MoneySign::Positive).unwrap();
-		let m2 = Money::from_str("$5.34").unwrap();
+	fn from_str_unicode_minus() {
+		let m = Money::from_str("\u{2212}5.34").unwrap();
 		
-		assert!(
-			m1 == m2 &&
-			m2.options_immutable().symbol() == '$' &&
-			m2.options_immutable().show_symbol() == true
-		);
+		assert_eq!(m, Money::new(5, 34, MoneySign::Negative).unwrap());
+		assert_eq!(m.to_string(), "-5.34");
+		assert_eq!(Money::from_str("\u{2212}$5.34").unwrap(), Money::from_cents(-534));
+		assert_eq!(Money::from_str("5,34\u{2212} €").unwrap(), Money::from_cents(-534));
+		assert!(Money::from_str("\u{2212}-5.34").is_err());
 	}
 	
 	#[test]
-	fn from_str_minus_no_symbol() {
-		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
-		let m2 = Money::from_str("-5.34").unwrap();
+	fn sort_key_matches_ord() {
+		/* a simple linear congruential generator keeps the test deterministic without dependencies */
+		let mut seed: u64 = 0x9E37_79B9_7F4A_7C15;
+		let mut next = move |n: u64| {
+			seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+			(seed >> 33) % n
+		};
 		
-		assert!(
-			m1 == m2 &&
-			m2.options_immutable().symbol() == '$' &&
-			m2.options_immutable().show_symbol() == false &&
-			m2.options_immutable().negative_view() == NegativeView::Minus
-		);
+		let mut values: Vec<Money> = (0..1000).map(|_| {
+			let dollars = match next(3) {
+				0 => { next(10) },
+				1 => { next(u32::MAX as u64) * next(u32::MAX as u64) },
+				_ => { u64::MAX - next(10) },
+			};
+			let sign = if next(2) == 0 { MoneySign::Positive } else { MoneySign::Negative };
+			
+			Money::new(dollars, next(100) as u8, sign).unwrap()
+		}).collect();
+		
+		values.push(Money::max_representable());
+		values.push(Money::min_representable());
+		values.push(Money::default());
+		
+		let mut by_ord = values.clone();
+		by_ord.sort();
+		values.sort_by_key(|m| m.sort_key());
+		
+		assert_eq!(values, by_ord);
+		assert!(values.windows(2).all(|w| (w[0].sort_key() < w[1].sort_key()) == (w[0] < w[1])));
 	}
 	
 	#[test]
-	fn from_str_minus_symbol() {
-		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
-		let m2 = Money::from_str("-$5.34").unwrap();
+	fn lerp_ends_and_midpoint() {
+		let m1 = Money::from_cents(-100);
+		let m2 = Money::from_cents(301);
 		
-		assert!(
-			m1 == m2 &&
-			m2.options_immutable().symbol() == '$' &&
-			m2.options_immutable().show_symbol() == true &&
-			m2.options_immutable().negative_view() == NegativeView::Minus
-		);
+		assert_eq!(m1.lerp(&m2, 0.0, RoundingMode::HalfUp).unwrap(), m1);
+		assert_eq!(m1.lerp(&m2, 1.0, RoundingMode::HalfUp).unwrap(), m2);
+		assert_eq!(m1.lerp(&m2, 0.5, RoundingMode::HalfUp).unwrap(), m1.midpoint(&m2, RoundingMode::HalfUp));
+		assert_eq!(m1.lerp(&m2, 0.5, RoundingMode::Floor).unwrap(), Money::from_cents(100));
+		assert_eq!(m2.lerp(&m1, 0.5, RoundingMode::HalfUp).unwrap(), Money::from_cents(101));
 	}
-
+	
 	#[test]
-	fn from_str_paren_no_symbol() {
-		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
-		let m2 = Money::from_str("(5.34)").unwrap();
+	fn lerp_clamps() {
+		let max = Money::max_representable();
+		let min = Money::min_representable();
 		
-		assert!(
-			m1 == m2 &&
-			m2.options_immutable().symbol() == '$' &&
-			m2.options_immutable().show_symbol() == false &&
-			m2.options_immutable().negative_view() == NegativeView::Paren
-		);
+		assert_eq!(min.lerp(&max, -1.0, RoundingMode::HalfUp).unwrap(), min);
+		assert_eq!(min.lerp(&max, f64::INFINITY, RoundingMode::HalfUp).unwrap(), max);
+		assert_eq!(max.lerp(&max, 0.3, RoundingMode::HalfUp).unwrap(), max);
+		assert!(min.lerp(&max, 0.999_999, RoundingMode::Ceiling).unwrap() <= max);
+		assert_eq!(min.lerp(&max, f64::NAN, RoundingMode::HalfUp), Err(MoneyError::NotFinite));
 	}
 	
 	#[test]
-	fn from_str_paren_symbol() {
-		let m1 = Money::new(5, 34, MoneySign::Negative).unwrap();
-		let m2 = Money::from_str("($5.34)").unwrap();
+	fn add_sub_minor_units() {
+		let mut m = Money::from_cents(150);
+		m.options().set_symbol('€');
 		
-		assert!(
-			m1 == m2 &&
-			m2.options_immutable().symbol() == '$' &&
-			m2.options_immutable().show_symbol() == true &&
-			m2.options_immutable().negative_view() == NegativeView::Paren
-		);
+		assert_eq!(m + 25, Money::from_cents(175));
+		assert_eq!(m - 25, Money::from_cents(125));
+		assert_eq!(m + -25, Money::from_cents(125));
+		assert_eq!((m - 175).to_string(), "-€0.25");
+		assert_eq!(Money::from_cents(-25) + 175, Money::from_cents(150));
+		assert_eq!((m - i64::MIN).sort_key(), 150 - i64::MIN as i128);
+		
+		m += 50;
+		assert_eq!(m, Money::from_cents(200));
+		m -= 300;
+		assert_eq!(m.to_string(), "-€1.00");
 	}
-
+	
 	#[test]
-	fn from_str_pos_diff_symbol() {
-		let m1 = Money::new(5, 34, MoneySign::Positive).unwrap();
-		let m2 = Money::from_str("£5.34").unwrap();
-		
-		assert!(
-			m1 == m2 &&
-			m2.options_immutable().symbol() == '£' &&
-			m2.options_immutable().show_symbol() == true
-		);
+	#[should_panic]
+	fn add_minor_units_overflow() {
+		let _ = Money::max_representable() + 1;
 	}
 	
 	#[test]
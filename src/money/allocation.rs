@@ -0,0 +1,410 @@
+use super::{Money, MoneyError, ChangeError, convert_money_to_wide, convert_wide_to_money};
+use super::rounding::RoundingMode;
+
+impl Money {
+	/// Breaks the magnitude of the amount down into the given denominations (in cents), greedily from largest to smallest.
+	///
+	/// Returns `(denomination, count)` pairs for each denomination used, largest first, or `None` if the
+	/// amount cannot be represented exactly by the denominations. The counts are wide enough for any
+	/// amount, even in single cents.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(3, 67, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(
+	///     m.breakdown(&[1, 5, 10, 25, 100, 500]),
+	///     Some(vec![(100, 3), (25, 2), (10, 1), (5, 1), (1, 2)])
+	/// );
+	/// ```
+	pub fn breakdown(&self, denominations: &[u32]) -> Option<Vec<(u32, u128)>> {
+		let mut remaining = convert_money_to_wide(self).unsigned_abs();
+		let mut sorted: Vec<u32> = denominations.iter().copied().filter(|d| *d > 0).collect();
+		sorted.sort_unstable_by(|a, b| b.cmp(a));
+		sorted.dedup();
+		
+		let mut counts = Vec::new();
+		
+		for d in sorted {
+			let count = remaining / d as u128;
+			
+			if count > 0 {
+				counts.push((d, count));
+				remaining -= count * d as u128;
+			}
+		}
+		
+		if remaining == 0 {
+			Some(counts)
+		} else {
+			None
+		}
+	}
+	
+	/// Breaks the magnitude of the amount down into the given denominations, greedily from largest
+	/// to smallest, returning each denomination used repeated by its count, largest first.
+	///
+	/// Denominations that aren't positive are ignored. Returns an error if the amount cannot be
+	/// represented exactly by the denominations, or if the change has too many pieces to be held.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// let coins = [1, 5, 10, 25].map(Money::from_cents);
+	/// let change = Money::from_cents(56).make_change(&coins).unwrap();
+	///
+	/// assert_eq!(change, [25, 25, 5, 1].map(Money::from_cents));
+	/// ```
+	pub fn make_change(&self, denominations: &[Money]) -> Result<Vec<Money>, ChangeError> {
+		let mut remaining = convert_money_to_wide(self).unsigned_abs();
+		let mut sorted: Vec<&Money> = denominations.iter().filter(|d| convert_money_to_wide(d) > 0).collect();
+		sorted.sort_by_key(|d| std::cmp::Reverse(convert_money_to_wide(d)));
+		
+		let mut counts = Vec::new();
+		
+		for d in sorted {
+			let value = convert_money_to_wide(d) as u128;
+			
+			counts.push((*d, remaining / value));
+			remaining %= value;
+		}
+		
+		if remaining != 0 {
+			return Err(ChangeError);
+		}
+		
+		/* check the total before allocating, so a huge count fails instead of aborting */
+		let total: u128 = counts.iter().map(|(_, count)| count).sum();
+		let mut change = Vec::new();
+		change.try_reserve_exact(usize::try_from(total).map_err(|_| ChangeError)?).map_err(|_| ChangeError)?;
+		
+		for (d, count) in counts {
+			change.extend(std::iter::repeat_n(d, count as usize));
+		}
+		
+		Ok(change)
+	}
+	
+	/// Returns an iterator over `parts` equal shares of the amount, which sum exactly to the amount.
+	///
+	/// Any remaining cents are spread one per share across the first shares. The options are kept,
+	/// and no shares are produced if `parts` is zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(10, 0, MoneySign::Positive).unwrap();
+	/// let shares: Vec<String> = m.allocate_iter(3).map(|s| s.to_string()).collect();
+	///
+	/// assert_eq!(shares, ["$3.34", "$3.33", "$3.33"]);
+	/// assert_eq!(m.allocate_iter(3).sum::<Money>(), m);
+	/// ```
+	pub fn allocate_iter(&self, parts: u32) -> impl Iterator<Item = Money> {
+		let whole = convert_money_to_wide(self);
+		let options = self.options;
+		
+		/* a zero divisor is never used, since the range is then empty */
+		let divisor = parts.max(1) as i128;
+		let share = whole / divisor;
+		let remainder = whole % divisor;
+		
+		(0..parts as i128).map(move |i| {
+			let extra = if i < remainder.abs() { remainder.signum() } else { 0 };
+			let mut m = convert_wide_to_money(share + extra).unwrap();
+			m.options = options;
+			m
+		})
+	}
+	
+	/// Allocates the amount across shares in proportion to `ratios`, such that the shares sum
+	/// exactly to the amount.
+	///
+	/// The remaining cents after truncating each share are given one per share to the shares with
+	/// the largest remainders (the largest remainder method), earlier shares first on a tie. The
+	/// options are kept. Returns `MoneyError::DivideByZero` if the ratios sum to zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// let shares = Money::from_cents(1000).allocate_ratios(&[1, 1, 1]).unwrap();
+	///
+	/// assert_eq!(shares, [Money::from_cents(334), Money::from_cents(333), Money::from_cents(333)]);
+	/// ```
+	pub fn allocate_ratios(&self, ratios: &[u32]) -> Result<Vec<Money>, MoneyError> {
+		let whole = convert_money_to_wide(self);
+		let total: i128 = ratios.iter().map(|r| *r as i128).sum();
+		
+		if total == 0 {
+			return Err(MoneyError::DivideByZero);
+		}
+		
+		let mut shares: Vec<i128> = ratios.iter().map(|r| whole * *r as i128 / total).collect();
+		let leftover = whole - shares.iter().sum::<i128>();
+		
+		/* the leftover is fewer cents than there are shares, and has the sign of the amount */
+		let mut order: Vec<usize> = (0..ratios.len()).collect();
+		order.sort_by_key(|i| std::cmp::Reverse((whole * ratios[*i] as i128 % total).abs()));
+		
+		for i in order.into_iter().take(leftover.unsigned_abs() as usize) {
+			shares[i] += leftover.signum();
+		}
+		
+		Ok(shares.into_iter().map(|share| {
+			let mut m = convert_wide_to_money(share).unwrap();
+			m.options = self.options;
+			m
+		}).collect())
+	}
+	
+	/// Distributes `total` across labeled buckets in proportion to their weights, such that the
+	/// amounts sum exactly to the total (see `allocate_ratios()`).
+	///
+	/// Returns `MoneyError::DivideByZero` if the weights sum to zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// let budget = Money::spread(Money::from_cents(100_000), &[("rent", 3), ("food", 2), ("fun", 1)]).unwrap();
+	///
+	/// assert_eq!(budget[0], ("rent".to_string(), Money::from_cents(50_000)));
+	/// assert_eq!(budget[2].1.to_string(), "$166.67");
+	/// ```
+	pub fn spread(total: Money, buckets: &[(&str, u32)]) -> Result<Vec<(String, Money)>, MoneyError> {
+		let ratios: Vec<u32> = buckets.iter().map(|(_, weight)| *weight).collect();
+		let shares = total.allocate_ratios(&ratios)?;
+		
+		Ok(buckets.iter().zip(shares).map(|((label, _), m)| (label.to_string(), m)).collect())
+	}
+	
+	/// Returns the average of the amounts in `items`, each weighted by its quantity, rounded with `mode`.
+	///
+	/// Returns `MoneyError::DivideByZero` if the total weight is zero, or `MoneyError::Overflow` if
+	/// the weighted total overflows.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::RoundingMode;
+	/// let lots = [
+	///     (Money::new(10, 0, MoneySign::Positive).unwrap(), 100),
+	///     (Money::new(12, 0, MoneySign::Positive).unwrap(), 50),
+	/// ];
+	///
+	/// assert_eq!(Money::weighted_average(&lots, RoundingMode::HalfEven).unwrap().to_string(), "$10.67");
+	/// ```
+	pub fn weighted_average(items: &[(Money, u64)], mode: RoundingMode) -> Result<Money, MoneyError> {
+		let mut total: i128 = 0;
+		let mut weight: i128 = 0;
+		
+		for (m, w) in items {
+			let weighted = convert_money_to_wide(m).checked_mul(*w as i128).ok_or(MoneyError::Overflow)?;
+			
+			total = total.checked_add(weighted).ok_or(MoneyError::Overflow)?;
+			weight += *w as i128;
+		}
+		
+		if weight == 0 {
+			return Err(MoneyError::DivideByZero);
+		}
+		
+		Ok(convert_wide_to_money(mode.divide(total, weight))?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::MoneySign;
+	
+	#[test]
+	fn breakdown() {
+		let m = Money::new(3, 67, MoneySign::Positive).unwrap();
+		
+		assert_eq!(
+			m.breakdown(&[1, 5, 10, 25, 100, 500, 1000, 2000]),
+			Some(vec![(100, 3), (25, 2), (10, 1), (5, 1), (1, 2)])
+		);
+	}
+	
+	#[test]
+	fn breakdown_unsorted_negative() {
+		let m = Money::new(3, 65, MoneySign::Negative).unwrap();
+		
+		assert_eq!(
+			m.breakdown(&[25, 500, 5, 100, 10]),
+			Some(vec![(100, 3), (25, 2), (10, 1), (5, 1)])
+		);
+	}
+	
+	#[test]
+	fn breakdown_inexact() {
+		let m = Money::new(3, 67, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.breakdown(&[5, 10, 25, 100]), None);
+		assert_eq!(m.breakdown(&[]), None);
+	}
+	
+	#[test]
+	fn breakdown_large_counts() {
+		assert_eq!(Money::max_representable().breakdown(&[1]), Some(vec![(1, u64::MAX as u128 * 100 + 99)]));
+		assert_eq!(Money::min_representable().breakdown(&[100, 1]), Some(vec![(100, u64::MAX as u128), (1, 99)]));
+	}
+	
+	#[test]
+	fn allocate_iter_sums_to_original() {
+		for cents in [0, 1, 99, 100, 1001, -1001, -7] {
+			let m = Money::from_cents(cents);
+			
+			for parts in 1..=7 {
+				let total = m.allocate_iter(parts).fold(Money::default(), |t, s| t + s);
+				
+				assert_eq!(total, m);
+				assert_eq!(m.allocate_iter(parts).count(), parts as usize);
+			}
+		}
+	}
+	
+	#[test]
+	fn allocate_iter_front_loaded() {
+		let mut m = Money::from_cents(-1001);
+		m.options.set_symbol('€');
+		let shares: Vec<String> = m.allocate_iter(4).map(|s| s.to_string()).collect();
+		
+		assert_eq!(shares, ["-€2.51", "-€2.50", "-€2.50", "-€2.50"]);
+	}
+	
+	#[test]
+	fn allocate_iter_zero_parts() {
+		assert_eq!(Money::from_cents(100).allocate_iter(0).count(), 0);
+	}
+	
+	#[test]
+	fn allocate_iter_max() {
+		let m = Money::max_representable();
+		
+		assert_eq!(m.allocate_iter(u32::MAX).take(2).count(), 2);
+		assert_eq!(Money::sum_checked(&m.allocate_iter(3).collect::<Vec<Money>>()).unwrap(), m);
+	}
+	
+	#[test]
+	fn weighted_average_lots() {
+		let lots = [
+			(Money::new(10, 0, MoneySign::Positive).unwrap(), 3),
+			(Money::new(11, 0, MoneySign::Positive).unwrap(), 1),
+		];
+		
+		assert_eq!(Money::weighted_average(&lots, RoundingMode::HalfEven).unwrap(), Money::from_cents(1025));
+		
+		let lots = [
+			(Money::new(10, 0, MoneySign::Positive).unwrap(), 2),
+			(Money::new(10, 1, MoneySign::Positive).unwrap(), 1),
+		];
+		
+		assert_eq!(Money::weighted_average(&lots, RoundingMode::Floor).unwrap(), Money::from_cents(1000));
+		assert_eq!(Money::weighted_average(&lots, RoundingMode::Ceiling).unwrap(), Money::from_cents(1001));
+	}
+	
+	#[test]
+	fn weighted_average_zero_weight() {
+		let lots = [(Money::new(10, 0, MoneySign::Positive).unwrap(), 0)];
+		
+		assert_eq!(Money::weighted_average(&lots, RoundingMode::HalfUp), Err(MoneyError::DivideByZero));
+		assert_eq!(Money::weighted_average(&[], RoundingMode::HalfUp), Err(MoneyError::DivideByZero));
+	}
+	
+	#[test]
+	fn weighted_average_large() {
+		let max = Money::max_representable();
+		let lots = [(max, 1_000_000), (max, 3)];
+		
+		assert_eq!(Money::weighted_average(&lots, RoundingMode::HalfUp).unwrap(), max);
+		
+		let lots = [(max, u64::MAX)];
+		
+		assert_eq!(Money::weighted_average(&lots, RoundingMode::HalfUp), Err(MoneyError::Overflow));
+	}
+	
+	#[test]
+	fn allocate_ratios() {
+		let m = Money::from_cents(-1001);
+		let shares = m.allocate_ratios(&[3, 0, 7]).unwrap();
+		
+		assert_eq!(shares, [Money::from_cents(-300), Money::from_cents(0), Money::from_cents(-701)]);
+		assert_eq!(shares.iter().copied().sum::<Money>(), m);
+		assert_eq!(Money::from_cents(5).allocate_ratios(&[1, 1, 1, 1]).unwrap().iter().copied().sum::<Money>(), Money::from_cents(5));
+		assert_eq!(m.allocate_ratios(&[0, 0]), Err(MoneyError::DivideByZero));
+		assert_eq!(m.allocate_ratios(&[]), Err(MoneyError::DivideByZero));
+	}
+	
+	#[test]
+	fn spread_sums_to_total() {
+		let mut total = Money::new(u64::MAX, 99, MoneySign::Negative).unwrap();
+		total.options().set_symbol('€');
+		
+		let buckets = [("a", u32::MAX), ("b", 7), ("c", 13), ("d", 1)];
+		let spread = Money::spread(total, &buckets).unwrap();
+		let amounts: Vec<Money> = spread.iter().map(|(_, m)| *m).collect();
+		
+		assert_eq!(Money::sum_checked(&amounts).unwrap(), total);
+		assert!(spread.iter().all(|(_, m)| m.options_immutable().symbol() == '€'));
+	}
+	
+	#[test]
+	fn spread_labels() {
+		let spread = Money::spread(Money::from_cents(1000), &[("x", 1), ("y", 1), ("z", 1)]).unwrap();
+		let labels: Vec<&str> = spread.iter().map(|(label, _)| label.as_str()).collect();
+		
+		assert_eq!(labels, ["x", "y", "z"]);
+		assert_eq!(spread[0].1, Money::from_cents(334));
+		assert_eq!(spread[2].1, Money::from_cents(333));
+		assert!(Money::spread(Money::from_cents(1000), &[("x", 0)]).is_err());
+	}
+	
+	#[test]
+	fn make_change_us_coins() {
+		let coins = [1, 5, 10, 25].map(Money::from_cents);
+		let change = Money::from_cents(99).make_change(&coins).unwrap();
+		
+		assert_eq!(change, [25, 25, 25, 10, 10, 1, 1, 1, 1].map(Money::from_cents));
+		assert_eq!(change.iter().copied().sum::<Money>(), Money::from_cents(99));
+		assert_eq!(Money::from_cents(-99).make_change(&coins).unwrap(), change);
+		assert!(Money::default().make_change(&coins).unwrap().is_empty());
+	}
+	
+	#[test]
+	fn make_change_large_counts() {
+		let penny = Money::from_cents(1);
+		
+		assert_eq!(Money::from_cents(10).make_change(&[penny, penny]).unwrap(), [penny; 10]);
+		assert_eq!(Money::from_dollars(10_000).make_change(&[penny]).unwrap().len(), 1_000_000);
+		assert!(Money::max_representable().make_change(&[penny]).is_err());
+	}
+	
+	#[test]
+	fn make_change_impossible() {
+		let coins = [5, 10, 25].map(Money::from_cents);
+		
+		assert!(Money::from_cents(99).make_change(&coins).is_err());
+		assert!(Money::from_cents(99).make_change(&[]).is_err());
+		assert!(Money::from_cents(5).make_change(&[Money::default(), Money::from_cents(-5)]).is_err());
+		assert_eq!(ChangeError.to_string(), "exact change is not possible");
+	}
+	
+	#[test]
+	fn make_change_keeps_denomination_options() {
+		let mut note = Money::from_dollars(5);
+		note.options().set_symbol('€');
+		
+		let change = Money::from_dollars(15).make_change(&[note]).unwrap();
+		
+		assert_eq!(change.len(), 3);
+		assert!(change.iter().all(|c| c.to_string() == "€5.00"));
+	}
+}
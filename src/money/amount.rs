@@ -0,0 +1,160 @@
+use super::{Money, MoneySign, MoneyErrorCents, MoneyErrorNegative};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::fmt;
+
+/// A non-negative Money value, for contexts such as prices where a negative amount is invalid.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Amount {
+	money: Money,
+}
+
+impl Amount {
+	/// Creates a new Amount instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Amount;
+	/// let a = Amount::new(5, 25).unwrap();
+	///
+	/// assert_eq!(a.to_string(), "$5.25");
+	/// ```
+	pub fn new(dollars: u64, cents: u8) -> Result<Self, MoneyErrorCents> {
+		Ok(
+			Self {
+				money: Money::new(dollars, cents, MoneySign::Positive)?,
+			}
+		)
+	}
+	
+	/// Returns the Amount as a positive-signed Money instance.
+	pub fn money(&self) -> Money {
+		self.money
+	}
+}
+
+impl TryFrom<Money> for Amount {
+	type Error = MoneyErrorNegative;
+	
+	/// Converts a Money instance into an Amount, or an error if the value is negative.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Amount, Money, MoneySign};
+	/// let m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	///
+	/// assert!(Amount::try_from(m).is_err());
+	/// ```
+	fn try_from(mut money: Money) -> Result<Self, Self::Error> {
+		if money < Money::default() {
+			return Err(MoneyErrorNegative);
+		}
+		
+		money.sign = MoneySign::Positive;  // prevents negative 0.00
+		
+		Ok(Self { money })
+	}
+}
+
+impl From<Amount> for Money {
+	fn from(amount: Amount) -> Self {
+		amount.money
+	}
+}
+
+impl Add for Amount {
+	type Output = Self;
+	
+	fn add(self, other: Self) -> Self {
+		Self {
+			money: self.money + other.money,
+		}
+	}
+}
+
+impl AddAssign for Amount {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Amount {
+	type Output = Self;
+	
+	/* saturates at zero rather than producing a negative value */
+	fn sub(self, other: Self) -> Self {
+		if self.money <= other.money {
+			Self::default()
+		} else {
+			Self {
+				money: self.money - other.money,
+			}
+		}
+	}
+}
+
+impl SubAssign for Amount {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.money)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+	
+	#[test]
+	fn try_from_positive() {
+		let m = Money::new(5, 25, MoneySign::Positive).unwrap();
+		
+		assert_eq!(Amount::try_from(m).unwrap().money(), m);
+	}
+	
+	#[test]
+	fn try_from_negative() {
+		let m = Money::new(5, 25, MoneySign::Negative).unwrap();
+		
+		assert!(Amount::try_from(m).is_err());
+	}
+	
+	#[test]
+	fn try_from_negative_zero() {
+		let a = Amount::try_from(-Money::default()).unwrap();
+		
+		assert_eq!(a.money().sign(), MoneySign::Positive);
+	}
+	
+	#[test]
+	fn add() {
+		let a1 = Amount::new(4, 56).unwrap();
+		let a2 = Amount::new(12, 49).unwrap();
+		
+		assert_eq!(a1 + a2, Amount::new(17, 5).unwrap());
+	}
+	
+	#[test]
+	fn sub() {
+		let a1 = Amount::new(12, 49).unwrap();
+		let a2 = Amount::new(4, 56).unwrap();
+		
+		assert_eq!(a1 - a2, Amount::new(7, 93).unwrap());
+	}
+	
+	#[test]
+	fn sub_saturating() {
+		let mut a1 = Amount::new(4, 56).unwrap();
+		let a2 = Amount::new(12, 49).unwrap();
+		
+		a1 -= a2;
+		
+		assert_eq!(a1, Amount::default());
+		assert_eq!(a1.money().sign(), MoneySign::Positive);
+	}
+}
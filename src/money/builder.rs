@@ -0,0 +1,145 @@
+use super::{Money, MoneySign, MoneyError};
+use super::options::NegativeView;
+
+/// Builds a Money instance and its options in one expression. Created with `Money::builder()`.
+#[derive(Debug, Copy, Clone)]
+pub struct MoneyBuilder {
+	dollars: u64,
+	cents: u8,
+	sign: MoneySign,
+	symbol: Option<char>,
+	show_symbol: Option<bool>,
+	negative_view: Option<NegativeView>,
+}
+
+impl MoneyBuilder {
+	pub(crate) fn new() -> Self {
+		Self {
+			dollars: 0,
+			cents: 0,
+			sign: MoneySign::Positive,
+			symbol: None,
+			show_symbol: None,
+			negative_view: None,
+		}
+	}
+	
+	/// Set the `dollars` value.  
+	/// Default: 0
+	pub fn dollars(mut self, dollars: u64) -> Self {
+		self.dollars = dollars;
+		self
+	}
+	
+	/// Set the `cents` value, which is validated by `build()`.  
+	/// Default: 0
+	pub fn cents(mut self, cents: u8) -> Self {
+		self.cents = cents;
+		self
+	}
+	
+	/// Set the `sign` value.  
+	/// Default: Positive
+	pub fn sign(mut self, sign: MoneySign) -> Self {
+		self.sign = sign;
+		self
+	}
+	
+	/// Set the money symbol, which is validated by `build()`.
+	pub fn symbol(mut self, symbol: char) -> Self {
+		self.symbol = Some(symbol);
+		self
+	}
+	
+	/// Set whether the money symbol is included in the string.
+	pub fn show_symbol(mut self, show_symbol: bool) -> Self {
+		self.show_symbol = Some(show_symbol);
+		self
+	}
+	
+	/// Set the negative representation to use.
+	pub fn negative_view(mut self, negative_view: NegativeView) -> Self {
+		self.negative_view = Some(negative_view);
+		self
+	}
+	
+	/// Creates the Money instance, or returns `MoneyError::Cents` or `MoneyError::Symbol` if a value is invalid.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign, MoneyError};
+	/// # use nmoney::money::options::NegativeView;
+	/// let m = Money::builder()
+	///     .dollars(5)
+	///     .cents(25)
+	///     .sign(MoneySign::Negative)
+	///     .symbol('£')
+	///     .negative_view(NegativeView::Paren)
+	///     .build()
+	///     .unwrap();
+	///
+	/// assert_eq!(m.to_string(), "(£5.25)");
+	/// assert_eq!(Money::builder().cents(100).build(), Err(MoneyError::Cents));
+	/// ```
+	pub fn build(self) -> Result<Money, MoneyError> {
+		let mut m = Money::new(self.dollars, self.cents, self.sign)?;
+		
+		if let Some(symbol) = self.symbol {
+			if !m.options().set_symbol(symbol) {
+				return Err(MoneyError::Symbol);
+			}
+		}
+		
+		if let Some(show_symbol) = self.show_symbol {
+			m.options().set_show_symbol(show_symbol);
+		}
+		
+		if let Some(negative_view) = self.negative_view {
+			m.options().set_negative_view(negative_view);
+		}
+		
+		Ok(m)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+	
+	#[test]
+	fn build_default() {
+		let m = Money::builder().build().unwrap();
+		
+		assert!(m.eq_exact(&Money::default()));
+	}
+	
+	#[test]
+	fn build_full() {
+		let m = Money::builder()
+			.dollars(5)
+			.cents(25)
+			.sign(MoneySign::Negative)
+			.symbol('£')
+			.show_symbol(true)
+			.negative_view(NegativeView::Paren)
+			.build()
+			.unwrap();
+		
+		let mut expected = Money::new(5, 25, MoneySign::Negative).unwrap();
+		expected.options().set_symbol('£');
+		expected.options().set_negative_view(NegativeView::Paren);
+		
+		assert!(m.eq_exact(&expected));
+	}
+	
+	#[test]
+	fn build_invalid_cents() {
+		assert_eq!(Money::builder().dollars(5).cents(100).build(), Err(MoneyError::Cents));
+	}
+	
+	#[test]
+	fn build_invalid_symbol() {
+		assert_eq!(Money::builder().dollars(5).symbol('1').build(), Err(MoneyError::Symbol));
+	}
+}
@@ -0,0 +1,87 @@
+/// ISO 4217 currencies recognized by the library.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Currency {
+	Usd,
+	Eur,
+	Gbp,
+	Jpy,
+	Cad,
+	Aud,
+	Inr,
+}
+
+impl Currency {
+	/// Returns the currency matching a three-letter ISO 4217 code (case-insensitive), if known.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Currency;
+	/// assert_eq!(Currency::from_code("eur"), Some(Currency::Eur));
+	/// assert_eq!(Currency::from_code("XYZ"), None);
+	/// ```
+	pub fn from_code(code: &str) -> Option<Self> {
+		match code.to_ascii_uppercase().as_str() {
+			"USD" => { Some(Currency::Usd) },
+			"EUR" => { Some(Currency::Eur) },
+			"GBP" => { Some(Currency::Gbp) },
+			"JPY" => { Some(Currency::Jpy) },
+			"CAD" => { Some(Currency::Cad) },
+			"AUD" => { Some(Currency::Aud) },
+			"INR" => { Some(Currency::Inr) },
+			_ => { None },
+		}
+	}
+	
+	/// Returns the currency named by an English word (case-insensitive, singular or plural), if known.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Currency;
+	/// assert_eq!(Currency::from_word("Euros"), Some(Currency::Eur));
+	/// assert_eq!(Currency::from_word("doubloons"), None);
+	/// ```
+	pub fn from_word(word: &str) -> Option<Self> {
+		match word.to_lowercase().as_str() {
+			"dollar" | "dollars" => { Some(Currency::Usd) },
+			"euro" | "euros" => { Some(Currency::Eur) },
+			"pound" | "pounds" => { Some(Currency::Gbp) },
+			"yen" => { Some(Currency::Jpy) },
+			"rupee" | "rupees" => { Some(Currency::Inr) },
+			_ => { None },
+		}
+	}
+	
+	/// Returns the three-letter ISO 4217 code of the currency.
+	pub fn code(&self) -> &'static str {
+		match self {
+			Currency::Usd => { "USD" },
+			Currency::Eur => { "EUR" },
+			Currency::Gbp => { "GBP" },
+			Currency::Jpy => { "JPY" },
+			Currency::Cad => { "CAD" },
+			Currency::Aud => { "AUD" },
+			Currency::Inr => { "INR" },
+		}
+	}
+	
+	/// Returns the symbol conventionally used for the currency.
+	pub fn symbol(&self) -> char {
+		match self {
+			Currency::Usd | Currency::Cad | Currency::Aud => { '$' },
+			Currency::Eur => { '€' },
+			Currency::Gbp => { '£' },
+			Currency::Jpy => { '¥' },
+			Currency::Inr => { '₹' },
+		}
+	}
+	
+	/// Returns the number of minor-unit digits the currency uses (e.g. 2 for cents, 0 for yen).
+	pub fn minor_digits(&self) -> u32 {
+		match self {
+			Currency::Jpy => { 0 },
+			_ => { 2 },
+		}
+	}
+}
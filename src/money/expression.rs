@@ -0,0 +1,180 @@
+use super::{Money, MoneyError, MoneyErrorString, MoneySign, convert_money_to_wide, convert_wide_to_money};
+use super::rounding::RoundingMode;
+
+impl Money {
+	/// Returns the signed percent change from `from` to this amount, or `None` if `from` is zero.
+	///
+	/// The result is never NaN or infinite.
+	///
+	/// The change is relative to the magnitude of `from`, so a decrease is always negative.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let from = Money::new(100, 0, MoneySign::Positive).unwrap();
+	/// let to = Money::new(125, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(to.percent_change(&from), Some(25.0));
+	/// ```
+	pub fn percent_change(&self, from: &Money) -> Option<f64> {
+		let base = convert_money_to_wide(from);
+		
+		if base == 0 {
+			return None;
+		}
+		
+		let change = convert_money_to_wide(self) - base;
+		
+		Some(change as f64 / base.unsigned_abs() as f64 * 100.0)
+	}
+	
+	/// Applies a simple expression to the amount: an operator ('+' or '-') followed by either an
+	/// amount (e.g. `"+2.00"`) or a percentage of the amount (e.g. `"+15%"`). The options are kept.
+	///
+	/// Percentages are rounded to the nearest cent, with halves rounded up. Returns `MoneyError::String`
+	/// if the expression is invalid, or `MoneyError::Overflow` if the result doesn't fit.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let bill = Money::new(40, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(bill.apply_expression("+15%").unwrap().to_string(), "$46.00");
+	/// assert_eq!(bill.apply_expression("-2.50").unwrap().to_string(), "$37.50");
+	/// assert!(bill.apply_expression("15%").is_err());
+	/// ```
+	pub fn apply_expression(&self, expr: &str) -> Result<Money, MoneyError> {
+		let expr = expr.trim();
+		let mut chars = expr.chars();
+		
+		let is_negative = match chars.next() {
+			Some('+') => { false },
+			Some('-') => { true },
+			_ => { return Err(MoneyError::String); },
+		};
+		
+		let operand = chars.as_str().trim_start();
+		
+		if !operand.starts_with(|c: char| c.is_ascii_digit()) {
+			return Err(MoneyError::String);
+		}
+		
+		let base = convert_money_to_wide(self);
+		let mut change = match operand.strip_suffix('%') {
+			Some(percent) => {
+				let (num, den) = parse_percent(percent)?;
+				let scaled = base.checked_mul(num).ok_or(MoneyError::Overflow)?;
+				
+				RoundingMode::HalfUp.divide(scaled, den)
+			},
+			None => {
+				let m = Money::from_str(operand)?;
+				
+				if m.sign == MoneySign::Negative {
+					return Err(MoneyError::String);
+				}
+				convert_money_to_wide(&m)
+			},
+		};
+		
+		if is_negative {
+			change = -change;
+		}
+		
+		let mut m = convert_wide_to_money(base + change)?;
+		m.options = self.options;
+		
+		Ok(m)
+	}
+}
+
+/* parses a non-negative decimal percentage into a numerator and denominator of a fraction */
+fn parse_percent(s: &str) -> Result<(i128, i128), MoneyErrorString> {
+	let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+	
+	if whole.is_empty() || (s.contains('.') && fraction.is_empty()) || fraction.len() > 9 {
+		return Err(MoneyErrorString);
+	}
+	
+	if !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+		return Err(MoneyErrorString);
+	}
+	
+	let scale = 10i128.pow(fraction.len() as u32);
+	let whole: i128 = whole.parse().map_err(|_| MoneyErrorString)?;
+	let fraction: i128 = if fraction.is_empty() { 0 } else { fraction.parse().unwrap() };
+	
+	match whole.checked_mul(scale).and_then(|w| w.checked_add(fraction)) {
+		Some(num) => { Ok((num, 100 * scale)) },
+		None => { Err(MoneyErrorString) },
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	
+	#[test]
+	fn percent_change_increase() {
+		let from = Money::new(100, 0, MoneySign::Positive).unwrap();
+		let to = Money::new(125, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(to.percent_change(&from), Some(25.0));
+	}
+	
+	#[test]
+	fn percent_change_decrease() {
+		let from = Money::new(100, 0, MoneySign::Negative).unwrap();
+		let to = Money::new(125, 0, MoneySign::Negative).unwrap();
+		
+		assert_eq!(to.percent_change(&from), Some(-25.0));
+	}
+	
+	#[test]
+	fn percent_change_from_zero() {
+		let to = Money::new(125, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(to.percent_change(&Money::default()), None);
+	}
+	
+	#[test]
+	fn apply_expression_amounts() {
+		let mut m = Money::new(10, 0, MoneySign::Positive).unwrap();
+		m.options.set_symbol('€');
+		
+		assert_eq!(m.apply_expression("+2.00").unwrap().to_string(), "€12.00");
+		assert_eq!(m.apply_expression("- 12.5").unwrap().to_string(), "-€2.50");
+	}
+	
+	#[test]
+	fn apply_expression_percentages() {
+		let m = Money::new(33, 33, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.apply_expression("+15%").unwrap(), Money::new(38, 33, MoneySign::Positive).unwrap());
+		assert_eq!(m.apply_expression("-10%").unwrap(), Money::new(30, 0, MoneySign::Positive).unwrap());
+		assert_eq!(m.apply_expression("+12.5%").unwrap(), Money::new(37, 50, MoneySign::Positive).unwrap());
+		assert_eq!(m.apply_expression("-100%").unwrap(), Money::default());
+	}
+	
+	#[test]
+	fn apply_expression_overflow() {
+		let max = Money::max_representable();
+		
+		assert_eq!(max.apply_expression("+1.00"), Err(MoneyError::Overflow));
+		assert_eq!(max.apply_expression("+99999999999999999999%"), Err(MoneyError::Overflow));
+		assert_eq!((-max).apply_expression("-0.01"), Err(MoneyError::Overflow));
+		assert_eq!(max.apply_expression("-100%").unwrap(), Money::default());
+		assert_eq!(max.apply_expression("+1.00.00"), Err(MoneyError::String));
+	}
+	
+	#[test]
+	fn apply_expression_invalid() {
+		let m = Money::new(10, 0, MoneySign::Positive).unwrap();
+		
+		for expr in ["", "+", "15%", "*2", "+-2", "+%", "+1.%", "+.5%", "+1.2.3%", "+15%%", "+(2.00)", "+abc"] {
+			assert!(m.apply_expression(expr).is_err(), "{expr}");
+		}
+	}
+}
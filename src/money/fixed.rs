@@ -0,0 +1,223 @@
+use super::{Money, MoneyError, MoneyErrorOverflow, STORED_MINOR_DIGITS, convert_money_to_wide, convert_wide_to_money};
+use super::rounding::RoundingMode;
+use std::ops::{Add, AddAssign, Sub, SubAssign, Neg};
+use std::fmt;
+
+/// An amount with `SCALE` minor digits fixed by its type, so amounts of different scales can't be
+/// mixed without an explicit conversion.
+///
+/// The amount is held as a whole number of minor units (10<sup>-SCALE</sup>). `SCALE` may not
+/// exceed 38.
+///
+/// A FixedMoney has no options, so it's displayed as a plain number, without a symbol. Convert it
+/// with `to_money()` for a formatted amount.
+///
+/// # Example
+///
+/// ```
+/// # use nmoney::{Money, MoneySign, FixedMoney, RoundingMode};
+/// let price = FixedMoney::<4>::from_minor_units(52_525);
+/// let fee = FixedMoney::<4>::from_money(&Money::new(1, 25, MoneySign::Positive).unwrap()).unwrap();
+///
+/// assert_eq!((price + fee).to_string(), "6.5025");
+/// assert_eq!((price + fee).to_money(RoundingMode::HalfUp).unwrap().to_string(), "$6.50");
+/// ```
+///
+/// Amounts of different scales can't be added directly:
+///
+/// ```compile_fail
+/// # use nmoney::FixedMoney;
+/// let total = FixedMoney::<2>::from_minor_units(525) + FixedMoney::<4>::from_minor_units(52_500);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedMoney<const SCALE: u32> {
+	minor: i128,
+}
+
+impl<const SCALE: u32> FixedMoney<SCALE> {
+	/* evaluated at compile time, so an unsupported scale fails to build */
+	const FACTOR: i128 = 10i128.pow(SCALE);
+	
+	/// Creates a new FixedMoney instance from a whole number of minor units.
+	pub fn from_minor_units(minor: i128) -> Self {
+		let _ = Self::FACTOR;
+		
+		Self { minor }
+	}
+	
+	/// Returns the amount as a whole number of minor units.
+	pub fn minor_units(&self) -> i128 {
+		self.minor
+	}
+	
+	/// Converts a Money instance exactly, returning `MoneyError::Scale` if the cents can't be held
+	/// at `SCALE` digits, or `MoneyError::Overflow` if the amount doesn't fit.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, FixedMoney};
+	/// assert_eq!(FixedMoney::<3>::from_money(&Money::from_cents(525)).unwrap().minor_units(), 5250);
+	/// assert!(FixedMoney::<0>::from_money(&Money::from_cents(525)).is_err());
+	/// ```
+	pub fn from_money(money: &Money) -> Result<Self, MoneyError> {
+		let whole = convert_money_to_wide(money);
+		
+		let minor = if SCALE >= STORED_MINOR_DIGITS {
+			whole.checked_mul(10i128.pow(SCALE - STORED_MINOR_DIGITS)).ok_or(MoneyError::Overflow)?
+		} else {
+			let step = 10i128.pow(STORED_MINOR_DIGITS - SCALE);
+			
+			if whole % step != 0 {
+				return Err(MoneyError::Scale);
+			}
+			whole / step
+		};
+		
+		Ok(Self::from_minor_units(minor))
+	}
+	
+	/// Converts the amount to a Money instance, rounding to the cent with `mode` when `SCALE`
+	/// exceeds two digits. Returns an error if the amount doesn't fit.
+	pub fn to_money(&self, mode: RoundingMode) -> Result<Money, MoneyErrorOverflow> {
+		let whole = if SCALE >= STORED_MINOR_DIGITS {
+			mode.divide(self.minor, 10i128.pow(SCALE - STORED_MINOR_DIGITS))
+		} else {
+			self.minor.checked_mul(10i128.pow(STORED_MINOR_DIGITS - SCALE)).ok_or(MoneyErrorOverflow)?
+		};
+		
+		convert_wide_to_money(whole)
+	}
+	
+	/// Converts the amount to another scale, rounding with `mode` when the scale is reduced.
+	/// Returns an error if the amount doesn't fit.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{FixedMoney, RoundingMode};
+	/// let m = FixedMoney::<4>::from_minor_units(52_550);
+	///
+	/// assert_eq!(m.rescale::<2>(RoundingMode::HalfEven).unwrap().minor_units(), 526);
+	/// assert_eq!(m.rescale::<6>(RoundingMode::HalfEven).unwrap().minor_units(), 5_255_000);
+	/// ```
+	pub fn rescale<const TO: u32>(&self, mode: RoundingMode) -> Result<FixedMoney<TO>, MoneyErrorOverflow> {
+		let minor = if TO >= SCALE {
+			self.minor.checked_mul(10i128.pow(TO - SCALE)).ok_or(MoneyErrorOverflow)?
+		} else {
+			mode.divide(self.minor, 10i128.pow(SCALE - TO))
+		};
+		
+		Ok(FixedMoney::<TO>::from_minor_units(minor))
+	}
+}
+
+impl<const SCALE: u32> Add for FixedMoney<SCALE> {
+	type Output = Self;
+	
+	fn add(self, other: Self) -> Self {
+		Self::from_minor_units(self.minor.checked_add(other.minor).expect("overflow on addition"))
+	}
+}
+
+impl<const SCALE: u32> AddAssign for FixedMoney<SCALE> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const SCALE: u32> Sub for FixedMoney<SCALE> {
+	type Output = Self;
+	
+	fn sub(self, other: Self) -> Self {
+		Self::from_minor_units(self.minor.checked_sub(other.minor).expect("overflow on subtraction"))
+	}
+}
+
+impl<const SCALE: u32> SubAssign for FixedMoney<SCALE> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const SCALE: u32> Neg for FixedMoney<SCALE> {
+	type Output = Self;
+	
+	fn neg(self) -> Self {
+		Self::from_minor_units(self.minor.checked_neg().expect("overflow on negation"))
+	}
+}
+
+impl<const SCALE: u32> fmt::Display for FixedMoney<SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let sign = if self.minor < 0 { "-" } else { "" };
+		let magnitude = self.minor.unsigned_abs();
+		let whole = magnitude / Self::FACTOR as u128;
+		
+		if SCALE == 0 {
+			write!(f, "{}{}", sign, whole)
+		} else {
+			let fraction = magnitude % Self::FACTOR as u128;
+			write!(f, "{}{}.{:0width$}", sign, whole, fraction, width = SCALE as usize)
+		}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+	use crate::MoneySign;
+	
+	#[test]
+	fn add_same_scale() {
+		let m1 = FixedMoney::<4>::from_minor_units(12_345);
+		let m2 = FixedMoney::<4>::from_minor_units(-2_345);
+		
+		assert_eq!(m1 + m2, FixedMoney::<4>::from_minor_units(10_000));
+		assert_eq!(m1 - m2, FixedMoney::<4>::from_minor_units(14_690));
+	}
+	
+	#[test]
+	fn add_after_conversion() {
+		let m2 = FixedMoney::<2>::from_minor_units(525);
+		let m4 = FixedMoney::<4>::from_minor_units(1);
+		
+		let total = m2.rescale::<4>(RoundingMode::HalfUp).unwrap() + m4;
+		
+		assert_eq!(total.minor_units(), 52_501);
+		assert_eq!(total.rescale::<2>(RoundingMode::HalfUp).unwrap() + m2, FixedMoney::<2>::from_minor_units(1050));
+	}
+	
+	#[test]
+	fn money_round_trip() {
+		let m = Money::new(1234, 56, MoneySign::Negative).unwrap();
+		
+		assert!(FixedMoney::<2>::from_money(&m).unwrap().to_money(RoundingMode::HalfUp).unwrap().eq_exact(&m));
+		assert!(FixedMoney::<8>::from_money(&m).unwrap().to_money(RoundingMode::HalfUp).unwrap().eq_exact(&m));
+		assert_eq!(FixedMoney::<1>::from_money(&m), Err(MoneyError::Scale));
+	}
+	
+	#[test]
+	fn to_money_rounding() {
+		let m = FixedMoney::<3>::from_minor_units(-5_255);
+		
+		assert_eq!(m.to_money(RoundingMode::HalfEven).unwrap(), Money::from_cents(-526));
+		assert_eq!(m.to_money(RoundingMode::Truncate).unwrap(), Money::from_cents(-525));
+		assert_eq!(FixedMoney::<0>::from_minor_units(5).to_money(RoundingMode::HalfUp).unwrap(), Money::from_cents(500));
+	}
+	
+	#[test]
+	fn overflow() {
+		assert!(FixedMoney::<38>::from_money(&Money::from_cents(200)).is_err());
+		assert!(FixedMoney::<0>::from_minor_units(i128::MAX).to_money(RoundingMode::HalfUp).is_err());
+		assert!(FixedMoney::<0>::from_minor_units(i128::MAX).rescale::<1>(RoundingMode::HalfUp).is_err());
+	}
+	
+	#[test]
+	fn display() {
+		assert_eq!(FixedMoney::<4>::from_minor_units(-52_501).to_string(), "-5.2501");
+		assert_eq!(FixedMoney::<4>::from_minor_units(5).to_string(), "0.0005");
+		assert_eq!(FixedMoney::<0>::from_minor_units(5).to_string(), "5");
+		assert_eq!(format!("{} BTC", FixedMoney::<8>::from_minor_units(12_345_678)), "0.12345678 BTC");
+	}
+}
\ No newline at end of file
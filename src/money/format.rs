@@ -0,0 +1,441 @@
+use super::{Money, MoneySign, convert_money_to_wide, group_digits};
+use super::options::{NegativeView, SymbolPosition};
+use super::rounding::RoundingMode;
+use super::locale::Locale;
+
+impl Money {
+	/// Returns the string representation with negative amounts in parentheses, regardless of the `negative_view` option.
+	///
+	/// The Money instance itself is left unchanged.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	///
+	/// assert_eq!(m.to_string(), "-$5.25");
+	/// assert_eq!(m.to_accounting_string(), "($5.25)");
+	/// ```
+	pub fn to_accounting_string(&self) -> String {
+		let mut m = *self;
+		m.options().set_negative_view(NegativeView::Paren);
+		m.to_string()
+	}
+	
+	/// Returns the string representation right-aligned to `width` characters, such that the decimal
+	/// separators of amounts aligned to the same width line up in a column.
+	///
+	/// With `NegativeView::Minus` and a prefix symbol, the sign marker sits in its own column at the
+	/// left, outside the symbol and any grouping. With the parentheses views, amounts that aren't
+	/// negative leave room for the closing parenthesis.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, GroupingStyle};
+	/// let mut m1 = Money::from_cents(123456);
+	/// let mut m2 = Money::from_cents(-525);
+	/// m1.options().set_grouping(Some(GroupingStyle::Thousands));
+	/// m2.options().set_grouping(Some(GroupingStyle::Thousands));
+	///
+	/// assert_eq!(m1.to_aligned_string(10), " $1,234.56");
+	/// assert_eq!(m2.to_aligned_string(10), "-    $5.25");
+	/// ```
+	pub fn to_aligned_string(&self, width: usize) -> String {
+		let options = self.options_immutable();
+		let mut s = self.to_string();
+		let is_zero = self.dollars == 0 && self.cents == 0;
+		let is_negative = self.sign == MoneySign::Negative && !is_zero;
+		let is_signed = is_negative || (options.force_sign() && !is_zero);
+		
+		match options.negative_view() {
+			NegativeView::Minus if options.symbol_position() == SymbolPosition::Prefix && !options.rtl() => {
+				/* a zero display starting with '-' or '+' is not a sign marker, so it stays in place */
+				let sign = match s.chars().next() {
+					Some(c @ ('-' | '+')) if is_signed => { s.remove(0); c },
+					_ => { ' ' },
+				};
+				format!["{}{:>width$}", sign, s, width = width.saturating_sub(1)]
+			},
+			NegativeView::Paren | NegativeView::ParenMinus if !is_negative && !options.rtl() => {
+				s.push(' ');
+				format!["{:>width$}", s, width = width]
+			},
+			_ => {
+				format!["{:>width$}", s, width = width]
+			},
+		}
+	}
+	
+	/// Returns the amount as a `(debit, credit)` pair of ledger columns, each right-aligned to `width`
+	/// characters. A negative amount fills the debit column and a positive amount the credit column,
+	/// showing the magnitude, while the other column is blank. Both columns are blank for zero.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// assert_eq!(Money::from_cents(-525).to_debit_credit_columns(8), ("   $5.25".to_string(), "        ".to_string()));
+	/// assert_eq!(Money::from_cents(525).to_debit_credit_columns(8), ("        ".to_string(), "   $5.25".to_string()));
+	/// ```
+	pub fn to_debit_credit_columns(&self, width: usize) -> (String, String) {
+		let blank = " ".repeat(width);
+		
+		/* the column gives the sign, so the magnitude is shown without a sign marker */
+		let mut magnitude = self.abs();
+		magnitude.options.set_force_sign(false);
+		magnitude.options.set_negative_view(NegativeView::Minus);
+		
+		let column = format!["{:>width$}", magnitude, width = width];
+		
+		match convert_money_to_wide(self).signum() {
+			-1 => { (column, blank) },
+			1 => { (blank, column) },
+			_ => { (blank.clone(), blank) },
+		}
+	}
+	
+	/// Returns the amount abbreviated to one decimal of a unit of thousands (K), millions (M),
+	/// billions (B) or trillions (T), rounded half up. Amounts under a thousand are not abbreviated.
+	///
+	/// The options are honored, except that one minor digit is always shown, and not raised.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// assert_eq!(Money::from_cents(123_456).to_compact_string(), "$1.2K");
+	/// assert_eq!(Money::from_cents(-987_654_321).to_compact_string(), "-$9.9M");
+	/// assert_eq!(Money::from_cents(99_999).to_compact_string(), "$999.99");
+	/// ```
+	pub fn to_compact_string(&self) -> String {
+		const UNITS: [(i128, char); 4] = [(1_000, 'K'), (1_000_000, 'M'), (1_000_000_000, 'B'), (1_000_000_000_000, 'T')];
+		
+		let magnitude = convert_money_to_wide(self).abs();
+		
+		let Some(mut i) = UNITS.iter().rposition(|(unit, _)| magnitude >= unit * 100) else {
+			return self.to_string();
+		};
+		
+		/* rounding may carry into the next unit (e.g. 999.95K is 1.0M) */
+		let mut tenths = RoundingMode::HalfUp.divide(magnitude, UNITS[i].0 * 10);
+		
+		if tenths >= 10_000 && i + 1 < UNITS.len() {
+			i += 1;
+			tenths = RoundingMode::HalfUp.divide(magnitude, UNITS[i].0 * 10);
+		}
+		
+		let mut m = *self;
+		m.dollars = (tenths / 10) as u64;
+		m.cents = (tenths % 10) as u8 * 10;
+		m.options.set_minor_digits(Some(1));
+		m.options.set_hide_zero_cents(false);
+		m.options.set_raised_minor(false);
+		m.options.set_force_two_decimals(false);
+		
+		let mut s = m.to_string();
+		let last = s.rfind(|c: char| c.is_ascii_digit()).unwrap();
+		s.insert(last + 1, UNITS[i].1);
+		s
+	}
+	
+	/// Returns the string representation if it fits within `width` characters, or else the compact
+	/// form (see `to_compact_string()`). If neither fits, the compact form is truncated with an
+	/// ellipsis.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::Money;
+	/// let m = Money::from_cents(123_456_789);
+	///
+	/// assert_eq!(m.to_fit(12), "$1234567.89");
+	/// assert_eq!(m.to_fit(8), "$1.2M");
+	/// assert_eq!(m.to_fit(4), "$1.…");
+	/// ```
+	pub fn to_fit(&self, width: usize) -> String {
+		let s = self.to_string();
+		
+		if s.chars().count() <= width {
+			return s;
+		}
+		
+		let compact = self.to_compact_string();
+		
+		if compact.chars().count() <= width {
+			compact
+		} else if width == 0 {
+			String::new()
+		} else {
+			let mut r: String = compact.chars().take(width - 1).collect();
+			r.push('…');
+			r
+		}
+	}
+	
+	/// Formats the amount with a template, for layouts the options don't provide.
+	///
+	/// The placeholders are:
+	/// * `{sign}` - '-' if negative, '+' if positive with `force_sign` set, or else nothing
+	/// * `{symbol}` - the money symbol
+	/// * `{code}` - the currency code, or nothing if no currency is set
+	/// * `{whole}` - the whole digits, grouped as set by the `grouping` option
+	/// * `{minor}` - the minor digits, as set by the `minor_digits` option
+	/// * `{decimal}` - the decimal separator
+	///
+	/// `{{` and `}}` produce literal braces. Any other text, including an unknown placeholder, is
+	/// copied as is.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let m = Money::new(1234, 5, MoneySign::Negative).unwrap();
+	///
+	/// assert_eq!(m.format_template("{sign}{symbol}{whole}.{minor}"), "-$1234.05");
+	/// assert_eq!(m.format_template("{whole}{{{minor}}}"), "1234{05}");
+	/// ```
+	pub fn format_template(&self, template: &str) -> String {
+		let options = self.options_immutable();
+		let (mut whole, minor) = self.format_parts();
+		
+		if let Some(style) = options.grouping() {
+			whole = group_digits(&whole, style, options.grouping_separator());
+		}
+		
+		let is_zero = self.dollars == 0 && self.cents == 0;
+		
+		let sign = if self.sign == MoneySign::Negative && !is_zero {
+			"-"
+		} else if options.force_sign() && !is_zero {
+			"+"
+		} else {
+			""
+		};
+		
+		let mut s = String::new();
+		let mut rest = template;
+		
+		while let Some(i) = rest.find(['{', '}']) {
+			s.push_str(&rest[..i]);
+			rest = &rest[i..];
+			
+			if rest.starts_with("{{") || rest.starts_with("}}") {
+				s.push_str(&rest[..1]);
+				rest = &rest[2..];
+				continue;
+			}
+			
+			let placeholder = rest.find('}').map(|end| &rest[..=end]);
+			
+			let value = match placeholder {
+				Some("{sign}") => { sign.to_string() },
+				Some("{symbol}") => { options.symbol_str().to_string() },
+				Some("{code}") => { options.currency().map_or(String::new(), |c| c.code().to_string()) },
+				Some("{whole}") => { whole.clone() },
+				Some("{minor}") => { minor.clone() },
+				Some("{decimal}") => { options.decimal_separator().to_string() },
+				_ => {
+					/* not a placeholder, so copy the brace as is */
+					s.push_str(&rest[..1]);
+					rest = &rest[1..];
+					continue;
+				},
+			};
+			
+			s.push_str(&value);
+			rest = &rest[placeholder.unwrap().len()..];
+		}
+		
+		s.push_str(rest);
+		s
+	}
+	
+	/// Returns the string of the amount formatted with the options of `locale`, without changing
+	/// the options of the instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign, Locale};
+	/// let m = Money::new(1234, 56, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(m.to_string_with_locale(Locale::DeDe), "1.234,56 €");
+	/// assert_eq!(m.to_string(), "$1234.56");
+	/// ```
+	pub fn to_string_with_locale(&self, locale: Locale) -> String {
+		let mut m = *self;
+		m.options = locale.options();
+		
+		m.to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Currency, GroupingStyle};
+	
+	#[test]
+	fn to_accounting_string_positive() {
+		let m = Money::new(12, 29, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.to_accounting_string(), "$12.29");
+	}
+	
+	#[test]
+	fn to_accounting_string_negative() {
+		let mut m = Money::new(12, 29, MoneySign::Negative).unwrap();
+		m.options().set_negative_view(NegativeView::Hide);
+		
+		assert_eq!(m.to_accounting_string(), "($12.29)");
+		assert_eq!(m.options_immutable().negative_view(), NegativeView::Hide);
+	}
+	
+	#[test]
+	fn to_string_with_locale() {
+		let mut m = Money::new(1234567, 89, MoneySign::Positive).unwrap();
+		m.options.set_symbol('£');
+		
+		assert_eq!(m.to_string_with_locale(Locale::EnUs), "$1,234,567.89");
+		assert_eq!(m.to_string_with_locale(Locale::DeDe), "1.234.567,89 €");
+		assert_eq!(m.to_string_with_locale(Locale::JaJp), "¥1,234,568");
+		assert_eq!(m.to_string_with_locale(Locale::Chf), "1'234'567.89 CHF");
+		assert_eq!(m.to_string(), "£1234567.89");
+	}
+	
+	#[test]
+	fn to_aligned_string() {
+		let mut m1 = Money::from_cents(123456789);
+		let mut m2 = Money::from_cents(-123456);
+		m1.options().set_grouping(Some(GroupingStyle::Thousands));
+		m2.options().set_grouping(Some(GroupingStyle::Thousands));
+		
+		let s1 = m1.to_aligned_string(14);
+		let s2 = m2.to_aligned_string(14);
+		
+		assert_eq!(s1, " $1,234,567.89");
+		assert_eq!(s2, "-    $1,234.56");
+		assert_eq!(s1.find('.'), s2.find('.'));
+		
+		m1.options().set_negative_view(NegativeView::Paren);
+		m2.options().set_negative_view(NegativeView::Paren);
+		
+		assert_eq!(m1.to_aligned_string(16), "  $1,234,567.89 ");
+		assert_eq!(m2.to_aligned_string(16), "     ($1,234.56)");
+	}
+	
+	#[test]
+	fn to_aligned_string_zero_display() {
+		let mut m = Money::default();
+		
+		m.options().set_zero_display(Some("-"));
+		assert_eq!(m.to_aligned_string(8), "       -");
+		
+		m.options().set_zero_display(Some("--"));
+		assert_eq!(m.to_aligned_string(8), "      --");
+		
+		m.options().set_force_sign(true);
+		assert_eq!(m.to_aligned_string(8), "      --");
+		assert_eq!(Money::from_cents(525).with_options_from(&m).to_aligned_string(8), "+  $5.25");
+	}
+	
+	#[test]
+	fn to_compact_string() {
+		assert_eq!(Money::from_cents(100_000).to_compact_string(), "$1.0K");
+		assert_eq!(Money::from_cents(99_995_000).to_compact_string(), "$1.0M");
+		assert_eq!(Money::from_cents(-4_560_000_000_000).to_compact_string(), "-$45.6B");
+		assert_eq!(Money::max_representable().to_compact_string(), "$18446744.1T");
+		
+		let mut m = Money::from_cents(-123_456);
+		m.options().set_symbol('€');
+		m.options().set_symbol_position(SymbolPosition::Suffix);
+		m.options().set_decimal_separator(',');
+		m.options().set_negative_view(NegativeView::Paren);
+		
+		assert_eq!(m.to_compact_string(), "(1,2K €)");
+	}
+	
+	#[test]
+	fn to_fit_fits() {
+		let m = Money::from_cents(-525);
+		
+		assert_eq!(m.to_fit(6), "-$5.25");
+		assert_eq!(m.to_fit(20), "-$5.25");
+	}
+	
+	#[test]
+	fn to_fit_compacts() {
+		let mut m = Money::from_cents(987_654_321);
+		m.options().set_grouping(Some(GroupingStyle::Thousands));
+		
+		assert_eq!(m.to_fit(13), "$9,876,543.21");
+		assert_eq!(m.to_fit(12), "$9.9M");
+		assert_eq!(m.to_fit(5), "$9.9M");
+	}
+	
+	#[test]
+	fn to_fit_truncates() {
+		let m = Money::from_cents(-987_654_321);
+		
+		assert_eq!(m.to_fit(5), "-$9.…");
+		assert_eq!(m.to_fit(1), "…");
+		assert_eq!(m.to_fit(0), "");
+		assert_eq!(Money::from_cents(525).to_fit(3), "$5…");
+	}
+	
+	#[test]
+	fn format_template() {
+		let mut m = Money::new(1234567, 8, MoneySign::Negative).unwrap();
+		m.options().set_grouping(Some(GroupingStyle::Thousands));
+		m.options().set_currency(Some(Currency::Eur));
+		m.options().set_decimal_separator(',');
+		
+		assert_eq!(m.format_template("{code} {sign}{whole}{decimal}{minor}"), "EUR -1,234,567,08");
+		assert_eq!(m.format_template("{whole}{minor} cents"), "1,234,56708 cents");
+		assert_eq!(m.format_template("no placeholders"), "no placeholders");
+		assert_eq!(m.format_template(""), "");
+		
+		let mut m = Money::from_cents(525);
+		m.options().set_force_sign(true);
+		
+		assert_eq!(m.format_template("[{sign}] {symbol}{whole}.{minor} {code}"), "[+] $5.25 ");
+		assert_eq!(Money::default().format_template("{sign}{whole}"), "0");
+	}
+	
+	#[test]
+	fn format_template_escaping() {
+		let m = Money::from_cents(-525);
+		
+		assert_eq!(m.format_template("{{sign}}"), "{sign}");
+		assert_eq!(m.format_template("{{{sign}}}"), "{-}");
+		assert_eq!(m.format_template("{unknown} {sign"), "{unknown} {sign");
+		assert_eq!(m.format_template("}{whole}{"), "}5{");
+		assert_eq!(m.format_template("{€}{minor}"), "{€}25");
+	}
+	
+	#[test]
+	fn to_debit_credit_columns() {
+		let mut m = Money::from_cents(123_456);
+		m.options().set_grouping(Some(GroupingStyle::Thousands));
+		
+		assert_eq!(m.to_debit_credit_columns(10), ("          ".to_string(), " $1,234.56".to_string()));
+		assert_eq!((-m).to_debit_credit_columns(10), (" $1,234.56".to_string(), "          ".to_string()));
+		assert_eq!(Money::default().to_debit_credit_columns(4), ("    ".to_string(), "    ".to_string()));
+		assert_eq!(Money::from_cents(-525).to_debit_credit_columns(2), ("$5.25".to_string(), "  ".to_string()));
+	}
+	
+	#[test]
+	fn to_debit_credit_columns_force_sign() {
+		let mut m = Money::from_cents(-525);
+		m.options().set_force_sign(true);
+		
+		assert_eq!(m.to_debit_credit_columns(8), ("   $5.25".to_string(), "        ".to_string()));
+		assert_eq!((-m).to_debit_credit_columns(8), ("        ".to_string(), "   $5.25".to_string()));
+		
+		m.options().set_negative_view(NegativeView::Paren);
+		
+		assert_eq!(m.to_debit_credit_columns(8), ("   $5.25".to_string(), "        ".to_string()));
+	}
+}
@@ -0,0 +1,87 @@
+use super::{Money, MoneyError, convert_f64_to_money, convert_money_to_wide};
+use super::rounding::RoundingMode;
+
+/// Day count conventions, which set the number of days in a year when accruing interest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DayCount {
+	/// Actual days elapsed over a 365-day year.
+	Actual365,
+	/// Days counted as 30-day months over a 360-day year.
+	Thirty360,
+}
+
+impl DayCount {
+	/// Returns the number of days in a year under the convention.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::DayCount;
+	/// assert_eq!(DayCount::Actual365.basis(), 365);
+	/// assert_eq!(DayCount::Thirty360.basis(), 360);
+	/// ```
+	pub fn basis(&self) -> u32 {
+		match self {
+			DayCount::Actual365 => { 365 },
+			DayCount::Thirty360 => { 360 },
+		}
+	}
+}
+
+impl Money {
+	/// Returns the simple interest accrued on the amount at `annual_rate` (e.g. 0.05 for 5%) over
+	/// `days`, with the year length set by `day_count`, rounding to the cent with `mode`.
+	///
+	/// The result keeps the options of the Money instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, DayCount, RoundingMode};
+	/// let principal = Money::from_dollars(10_000);
+	///
+	/// assert_eq!(principal.simple_interest(0.05, 90, DayCount::Thirty360, RoundingMode::HalfUp).unwrap().to_string(), "$125.00");
+	/// ```
+	pub fn simple_interest(&self, annual_rate: f64, days: u32, day_count: DayCount, mode: RoundingMode) -> Result<Money, MoneyError> {
+		let accrual = annual_rate * days as f64 / day_count.basis() as f64;
+		
+		let mut m = convert_f64_to_money(convert_money_to_wide(self) as f64 * accrual, mode)?;
+		m.options = self.options;
+		
+		Ok(m)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	
+	#[test]
+	fn basis() {
+		assert_eq!(DayCount::Actual365.basis(), 365);
+		assert_eq!(DayCount::Thirty360.basis(), 360);
+	}
+	
+	#[test]
+	fn simple_interest_day_counts() {
+		let principal = Money::from_dollars(10_000);
+		let actual = principal.simple_interest(0.05, 90, DayCount::Actual365, RoundingMode::HalfUp).unwrap();
+		let thirty = principal.simple_interest(0.05, 90, DayCount::Thirty360, RoundingMode::HalfUp).unwrap();
+		
+		assert_eq!(actual, Money::from_cents(12_329));
+		assert_eq!(thirty, Money::from_cents(12_500));
+		assert!(thirty > actual);
+		assert_eq!(principal.simple_interest(0.05, 90, DayCount::Actual365, RoundingMode::Truncate).unwrap(), Money::from_cents(12_328));
+	}
+	
+	#[test]
+	fn simple_interest_edges() {
+		let mut principal = Money::from_cents(-100_000);
+		principal.options().set_symbol('€');
+		
+		assert_eq!(principal.simple_interest(0.1, 365, DayCount::Actual365, RoundingMode::HalfUp).unwrap().to_string(), "-€100.00");
+		assert_eq!(principal.simple_interest(0.1, 0, DayCount::Thirty360, RoundingMode::HalfUp).unwrap(), Money::default());
+		assert_eq!(principal.simple_interest(f64::NAN, 90, DayCount::Thirty360, RoundingMode::HalfUp), Err(MoneyError::NotFinite));
+		assert_eq!(Money::max_representable().simple_interest(2.0, 360, DayCount::Thirty360, RoundingMode::HalfUp), Err(MoneyError::Overflow));
+	}
+}
@@ -0,0 +1,126 @@
+use super::options::{Options, SymbolPosition, GroupingStyle};
+use super::currency::Currency;
+
+/// Regional formatting presets, each providing a set of options.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Locale {
+	/// Swiss francs, e.g. `1'234.56 CHF`.
+	Chf,
+	/// United States dollars, e.g. `$1,234.56`.
+	EnUs,
+	/// Euros in Germany, e.g. `1.234,56 €`.
+	DeDe,
+	/// Japanese yen, without minor units, e.g. `¥1,235`.
+	JaJp,
+}
+
+impl Locale {
+	/// Returns the options used to format amounts in the locale.
+	///
+	/// The strings produced parse back with `Money::from_str()`. For `JaJp`, the amount is shown
+	/// rounded to whole yen, so the parsed value is the rounded amount.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign, Locale};
+	/// let mut m = Money::new(1234567, 89, MoneySign::Positive).unwrap();
+	/// *m.options() = Locale::Chf.options();
+	///
+	/// assert_eq!(m.to_string(), "1'234'567.89 CHF");
+	/// ```
+	pub fn options(&self) -> Options {
+		let mut options = Options::new();
+		
+		match self {
+			Locale::Chf => {
+				options.set_symbol_str("CHF");
+				options.set_symbol_position(SymbolPosition::Suffix);
+				options.set_grouping(Some(GroupingStyle::Thousands));
+				options.set_grouping_separator('\'');
+			},
+			Locale::EnUs => {
+				options.set_currency(Some(Currency::Usd));
+				options.set_grouping(Some(GroupingStyle::Thousands));
+			},
+			Locale::DeDe => {
+				options.set_currency(Some(Currency::Eur));
+				options.set_symbol(Currency::Eur.symbol());
+				options.set_symbol_position(SymbolPosition::Suffix);
+				options.set_decimal_separator(',');
+				options.set_grouping(Some(GroupingStyle::Thousands));
+				options.set_grouping_separator('.');
+			},
+			Locale::JaJp => {
+				options.set_currency(Some(Currency::Jpy));
+				options.set_symbol(Currency::Jpy.symbol());
+				options.set_minor_digits(Some(Currency::Jpy.minor_digits()));
+				options.set_grouping(Some(GroupingStyle::Thousands));
+			},
+		}
+		
+		options
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Money, MoneySign};
+	
+	#[test]
+	fn chf_options() {
+		let mut m = Money::new(1234, 56, MoneySign::Negative).unwrap();
+		*m.options() = Locale::Chf.options();
+		
+		assert_eq!(m.to_string(), "1'234.56- CHF");
+	}
+	
+	#[test]
+	fn en_us_options() {
+		let mut m = Money::new(1234, 56, MoneySign::Negative).unwrap();
+		*m.options() = Locale::EnUs.options();
+		
+		assert_eq!(m.to_string(), "-$1,234.56");
+	}
+	
+	#[test]
+	fn de_de_options() {
+		let mut m = Money::new(1234, 56, MoneySign::Negative).unwrap();
+		*m.options() = Locale::DeDe.options();
+		
+		assert_eq!(m.to_string(), "1.234,56- €");
+	}
+	
+	#[test]
+	fn ja_jp_options() {
+		let mut m = Money::new(1234, 56, MoneySign::Positive).unwrap();
+		*m.options() = Locale::JaJp.options();
+		
+		assert_eq!(m.to_string(), "¥1,235");
+	}
+	
+	#[test]
+	fn round_trip() {
+		for locale in [Locale::Chf, Locale::EnUs, Locale::DeDe] {
+			for cents in [123456, -123456, 100, 1234567890] {
+				let mut m = Money::from_cents(cents);
+				*m.options() = locale.options();
+				
+				let r = Money::from_str(&m.to_string()).unwrap();
+				
+				assert_eq!(r, m);
+				assert_eq!(r.to_string(), m.to_string());
+			}
+		}
+		
+		/* yen are shown rounded to whole units, so only the string survives */
+		let mut m = Money::from_cents(123456);
+		*m.options() = Locale::JaJp.options();
+		
+		let r = Money::from_str(&m.to_string()).unwrap();
+		
+		assert_eq!(r, Money::from_dollars(1235));
+		assert_eq!(r.to_string(), m.to_string());
+	}
+}
\ No newline at end of file
@@ -1,34 +1,343 @@
+use super::currency::Currency;
+use super::{MoneyError, STORED_MINOR_DIGITS};
+
 const DEFAULT_SYMBOL: char = '$';
 const DEFAULT_SHOW_SYMBOL: bool = true;
 const DEFAULT_NEGATIVE_VIEW: NegativeView = NegativeView::Minus;
+const DEFAULT_FORCE_SIGN: bool = false;
+const DEFAULT_DECIMAL_SEPARATOR: char = '.';
+const DEFAULT_CURRENCY: Option<Currency> = None;
+const DEFAULT_SYMBOL_POSITION: SymbolPosition = SymbolPosition::Prefix;
+const DEFAULT_HIDE_ZERO_CENTS: bool = false;
+const DEFAULT_MINOR_DIGITS: Option<u32> = None;
+const DEFAULT_GROUPING: Option<GroupingStyle> = None;
+const DEFAULT_GROUPING_SEPARATOR: char = ',';
+const DEFAULT_RTL: bool = false;
+const DEFAULT_ZERO_DISPLAY: Option<ZeroDisplay> = None;
+const DEFAULT_RAISED_MINOR: bool = false;
+const DEFAULT_SYMBOL_SPACING: Option<bool> = None;
+const DEFAULT_GROUP_MINOR: bool = false;
+const DEFAULT_MINOR_GROUPING_SEPARATOR: char = ' ';
+const DEFAULT_SUB_DOLLAR_AS_MINOR: bool = false;
+const DEFAULT_MINOR_SYMBOL: char = '¢';
+const DEFAULT_FORCE_TWO_DECIMALS: bool = false;
+const DEFAULT_NBSP: bool = false;
+const DEFAULT_NEGATIVE_SIGN_POSITION: NegativeSignPosition = NegativeSignPosition::BeforeSymbol;
+const MAX_SYMBOL_LEN: usize = 16;
+const MAX_ZERO_DISPLAY_LEN: usize = 32;
+const MAX_MINOR_DIGITS: u32 = 18;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum NegativeView {
 	Minus,
 	Paren,
 	Hide,
+	ParenMinus,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SymbolPosition {
+	Prefix,
+	Suffix,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NegativeSignPosition {
+	/// The sign marker is outside the symbol (e.g. -$5.25).
+	BeforeSymbol,
+	/// The sign marker is between the symbol and the number (e.g. $-5.25).
+	AfterSymbol,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GroupingStyle {
+	/// Groups of three digits (e.g. 1,234,567).
+	Thousands,
+	/// Three digits, then groups of two (e.g. 12,34,567).
+	Indian,
+}
+
+/* a short string stored inline (UTF-8), so that Options remains Copy */
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct InlineStr<const N: usize> {
+	bytes: [u8; N],
+	len: usize,
+}
+
+impl<const N: usize> InlineStr<N> {
+	fn copy_from(s: &str) -> Option<Self> {
+		if s.len() > N {
+			return None;
+		}
+		
+		let mut bytes = [0; N];
+		bytes[..s.len()].copy_from_slice(s.as_bytes());
+		
+		Some(Self { bytes, len: s.len() })
+	}
+	
+	fn as_str(&self) -> &str {
+		std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+	}
+}
+
+type Symbol = InlineStr<MAX_SYMBOL_LEN>;
+type ZeroDisplay = InlineStr<MAX_ZERO_DISPLAY_LEN>;
+
+impl Symbol {
+	fn new(s: &str) -> Option<Self> {
+		if s.is_empty() || s.chars().any(|c| c.is_ascii_digit()) {
+			return None;
+		}
+		
+		Self::copy_from(s)
+	}
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Options {
-	symbol: char,
+	symbol: Symbol,
 	show_symbol: bool,
 	negative_view: NegativeView,
+	force_sign: bool,
+	decimal_separator: char,
+	currency: Option<Currency>,
+	symbol_position: SymbolPosition,
+	hide_zero_cents: bool,
+	minor_digits: Option<u32>,
+	grouping: Option<GroupingStyle>,
+	grouping_separator: char,
+	rtl: bool,
+	zero_display: Option<ZeroDisplay>,
+	raised_minor: bool,
+	symbol_spacing: Option<bool>,
+	group_minor: bool,
+	minor_grouping_separator: char,
+	sub_dollar_as_minor: bool,
+	minor_symbol: char,
+	force_two_decimals: bool,
+	nbsp: bool,
+	negative_sign_position: NegativeSignPosition,
+}
+
+/// Every option as plain data, for persisting options (see `Options::snapshot()`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptionsSnapshot {
+	pub symbol: String,
+	pub show_symbol: bool,
+	pub negative_view: NegativeView,
+	pub force_sign: bool,
+	pub decimal_separator: char,
+	pub currency: Option<Currency>,
+	pub symbol_position: SymbolPosition,
+	pub hide_zero_cents: bool,
+	pub minor_digits: Option<u32>,
+	pub grouping: Option<GroupingStyle>,
+	pub grouping_separator: char,
+	pub rtl: bool,
+	pub zero_display: Option<String>,
+	pub raised_minor: bool,
+	pub symbol_spacing: Option<bool>,
+	pub group_minor: bool,
+	pub minor_grouping_separator: char,
+	pub sub_dollar_as_minor: bool,
+	pub minor_symbol: char,
+	pub force_two_decimals: bool,
+	pub nbsp: bool,
+	pub negative_sign_position: NegativeSignPosition,
 }
 
 impl Options {
 	#[doc(hidden)]
 	pub fn new() -> Self {
 		Self {
-			symbol: DEFAULT_SYMBOL,
+			symbol: Symbol::new(DEFAULT_SYMBOL.encode_utf8(&mut [0; 4])).unwrap(),
 			show_symbol: DEFAULT_SHOW_SYMBOL,
 			negative_view: DEFAULT_NEGATIVE_VIEW,
+			force_sign: DEFAULT_FORCE_SIGN,
+			decimal_separator: DEFAULT_DECIMAL_SEPARATOR,
+			currency: DEFAULT_CURRENCY,
+			symbol_position: DEFAULT_SYMBOL_POSITION,
+			hide_zero_cents: DEFAULT_HIDE_ZERO_CENTS,
+			minor_digits: DEFAULT_MINOR_DIGITS,
+			grouping: DEFAULT_GROUPING,
+			grouping_separator: DEFAULT_GROUPING_SEPARATOR,
+			rtl: DEFAULT_RTL,
+			zero_display: DEFAULT_ZERO_DISPLAY,
+			raised_minor: DEFAULT_RAISED_MINOR,
+			symbol_spacing: DEFAULT_SYMBOL_SPACING,
+			group_minor: DEFAULT_GROUP_MINOR,
+			minor_grouping_separator: DEFAULT_MINOR_GROUPING_SEPARATOR,
+			sub_dollar_as_minor: DEFAULT_SUB_DOLLAR_AS_MINOR,
+			minor_symbol: DEFAULT_MINOR_SYMBOL,
+			force_two_decimals: DEFAULT_FORCE_TWO_DECIMALS,
+			nbsp: DEFAULT_NBSP,
+			negative_sign_position: DEFAULT_NEGATIVE_SIGN_POSITION,
 		}
 	}
 	
-	/// Returns the current money symbol in use.
+	/// Restores every option to its default.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::NegativeView;
+	/// let mut m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	/// m.options().set_symbol('€');
+	/// m.options().set_negative_view(NegativeView::Paren);
+	/// m.options().reset();
+	///
+	/// assert_eq!(m.to_string(), "-$5.25");
+	/// ```
+	pub fn reset(&mut self) {
+		*self = Options::new();
+	}
+	
+	/// Returns every option as plain data, which `from_snapshot()` restores.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::Options;
+	/// let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// m.options().set_symbol_str("US$");
+	///
+	/// let snapshot = m.options().snapshot();
+	///
+	/// assert_eq!(snapshot.symbol, "US$");
+	/// assert_eq!(Options::from_snapshot(snapshot).unwrap(), *m.options());
+	/// ```
+	pub fn snapshot(&self) -> OptionsSnapshot {
+		OptionsSnapshot {
+			symbol: self.symbol_str().to_string(),
+			show_symbol: self.show_symbol,
+			negative_view: self.negative_view,
+			force_sign: self.force_sign,
+			decimal_separator: self.decimal_separator,
+			currency: self.currency,
+			symbol_position: self.symbol_position,
+			hide_zero_cents: self.hide_zero_cents,
+			minor_digits: self.minor_digits,
+			grouping: self.grouping,
+			grouping_separator: self.grouping_separator,
+			rtl: self.rtl,
+			zero_display: self.zero_display().map(String::from),
+			raised_minor: self.raised_minor,
+			symbol_spacing: self.symbol_spacing,
+			group_minor: self.group_minor,
+			minor_grouping_separator: self.minor_grouping_separator,
+			sub_dollar_as_minor: self.sub_dollar_as_minor,
+			minor_symbol: self.minor_symbol,
+			force_two_decimals: self.force_two_decimals,
+			nbsp: self.nbsp,
+			negative_sign_position: self.negative_sign_position,
+		}
+	}
+	
+	/// Restores options from a snapshot, validating each value as its setter does.
+	///
+	/// Returns `MoneyError::Symbol` if a symbol or separator is invalid, `MoneyError::Scale` if the
+	/// minor digits are out of range, and `MoneyError::String` if the zero display is too long.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::money::options::Options;
+	/// let mut snapshot = Options::new().snapshot();
+	/// snapshot.symbol = String::from("7");
+	///
+	/// assert!(Options::from_snapshot(snapshot).is_err());
+	/// ```
+	pub fn from_snapshot(snapshot: OptionsSnapshot) -> Result<Self, MoneyError> {
+		let mut options = Options::new();
+		
+		if !options.set_symbol_str(&snapshot.symbol) ||
+			!options.set_decimal_separator(snapshot.decimal_separator) ||
+			!options.set_grouping_separator(snapshot.grouping_separator) ||
+			!options.set_minor_grouping_separator(snapshot.minor_grouping_separator) ||
+			!options.set_minor_symbol(snapshot.minor_symbol) {
+			return Err(MoneyError::Symbol);
+		}
+		
+		if !options.set_minor_digits(snapshot.minor_digits) {
+			return Err(MoneyError::Scale);
+		}
+		
+		if !options.set_zero_display(snapshot.zero_display.as_deref()) {
+			return Err(MoneyError::String);
+		}
+		
+		options.set_show_symbol(snapshot.show_symbol);
+		options.set_negative_view(snapshot.negative_view);
+		options.set_force_sign(snapshot.force_sign);
+		options.set_currency(snapshot.currency);
+		options.set_symbol_position(snapshot.symbol_position);
+		options.set_hide_zero_cents(snapshot.hide_zero_cents);
+		options.set_grouping(snapshot.grouping);
+		options.set_rtl(snapshot.rtl);
+		options.set_raised_minor(snapshot.raised_minor);
+		options.set_symbol_spacing(snapshot.symbol_spacing);
+		options.set_group_minor(snapshot.group_minor);
+		options.set_sub_dollar_as_minor(snapshot.sub_dollar_as_minor);
+		options.set_force_two_decimals(snapshot.force_two_decimals);
+		options.set_nbsp(snapshot.nbsp);
+		options.set_negative_sign_position(snapshot.negative_sign_position);
+		
+		Ok(options)
+	}
+	
+	/// Returns options for a cryptocurrency-style amount, with `code` following the amount
+	/// as the symbol and `decimals` minor digits shown.
+	///
+	/// Money stores whole cents, so `decimals` cannot exceed two; a greater number of decimals
+	/// returns `MoneyError::Scale`. Amounts needing more precision, such as BTC's eight decimals,
+	/// can be held in a `FixedMoney` of that scale instead. An invalid code returns `MoneyError::Symbol`.
+	///
+	/// With two decimals, the string parses back with `Money::from_str()` if the code is three
+	/// to five capital letters.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::Options;
+	/// let mut m = Money::new(1, 23, MoneySign::Positive).unwrap();
+	/// *m.options() = Options::crypto("BTC", 2).unwrap();
+	///
+	/// assert_eq!(m.to_string(), "1.23 BTC");
+	/// assert!(Money::from_str("1.23 BTC").unwrap().eq_exact(&m));
+	/// assert!(Options::crypto("BTC", 8).is_err());
+	/// ```
+	pub fn crypto(code: &str, decimals: u32) -> Result<Self, MoneyError> {
+		if decimals > STORED_MINOR_DIGITS {
+			return Err(MoneyError::Scale);
+		}
+		
+		let mut options = Options::new();
+		
+		if !options.set_symbol_str(code) {
+			return Err(MoneyError::Symbol);
+		}
+		
+		options.set_symbol_position(SymbolPosition::Suffix);
+		
+		if decimals != STORED_MINOR_DIGITS {
+			options.set_minor_digits(Some(decimals));
+		}
+		
+		Ok(options)
+	}
+	
+	/// Returns the current money symbol in use (the first character, for a multi-character symbol).
 	pub fn symbol(&self) -> char {
-		self.symbol
+		self.symbol.as_str().chars().next().unwrap()
+	}
+	
+	/// Returns the current money symbol in use, as a string.
+	pub fn symbol_str(&self) -> &str {
+		self.symbol.as_str()
 	}
 	
 	/// Returns whether the money symbol is enabled in the return string.
@@ -41,6 +350,101 @@ impl Options {
 		self.negative_view
 	}
 	
+	/// Returns whether positive amounts are prefixed with a plus sign.
+	pub fn force_sign(&self) -> bool {
+		self.force_sign
+	}
+	
+	/// Returns the character separating dollars from cents.
+	pub fn decimal_separator(&self) -> char {
+		self.decimal_separator
+	}
+	
+	/// Returns the currency the value is tagged with, if any.
+	pub fn currency(&self) -> Option<Currency> {
+		self.currency
+	}
+	
+	/// Returns whether the money symbol is placed before or after the amount.
+	pub fn symbol_position(&self) -> SymbolPosition {
+		self.symbol_position
+	}
+	
+	/// Returns whether the cents are omitted from the string for whole amounts.
+	pub fn hide_zero_cents(&self) -> bool {
+		self.hide_zero_cents
+	}
+	
+	/// Returns the number of minor digits forced in the string, if any.
+	pub fn minor_digits(&self) -> Option<u32> {
+		self.minor_digits
+	}
+	
+	/// Returns the digit grouping applied to the dollars, if any.
+	pub fn grouping(&self) -> Option<GroupingStyle> {
+		self.grouping
+	}
+	
+	/// Returns the character separating digit groups.
+	pub fn grouping_separator(&self) -> char {
+		self.grouping_separator
+	}
+	
+	/// Returns whether the string is wrapped in right-to-left marks.
+	pub fn rtl(&self) -> bool {
+		self.rtl
+	}
+	
+	/// Returns the string shown in place of a zero amount, if any.
+	pub fn zero_display(&self) -> Option<&str> {
+		self.zero_display.as_ref().map(ZeroDisplay::as_str)
+	}
+	
+	/// Returns whether the minor digits are raised (superscript) in the string.
+	pub fn raised_minor(&self) -> bool {
+		self.raised_minor
+	}
+	
+	/// Returns whether a space separates the symbol and the amount, if set.
+	pub fn symbol_spacing(&self) -> Option<bool> {
+		self.symbol_spacing
+	}
+	
+	/// Returns whether the space separating the symbol and the amount is a non-breaking space.
+	pub fn nbsp(&self) -> bool {
+		self.nbsp
+	}
+	
+	/// Returns whether the sign marker is placed before or after the symbol.
+	pub fn negative_sign_position(&self) -> NegativeSignPosition {
+		self.negative_sign_position
+	}
+	
+	/// Returns whether the minor digits are grouped in threes.
+	pub fn group_minor(&self) -> bool {
+		self.group_minor
+	}
+	
+	/// Returns the character separating groups of minor digits.
+	pub fn minor_grouping_separator(&self) -> char {
+		self.minor_grouping_separator
+	}
+	
+	/// Returns whether amounts under a dollar are shown in minor units, with the minor symbol.
+	pub fn sub_dollar_as_minor(&self) -> bool {
+		self.sub_dollar_as_minor
+	}
+	
+	/// Returns the symbol shown after amounts in minor units.
+	pub fn minor_symbol(&self) -> char {
+		self.minor_symbol
+	}
+	
+	/// Returns whether two minor digits are always shown, overriding `minor_digits` and `hide_zero_cents`.
+	pub fn force_two_decimals(&self) -> bool {
+		self.force_two_decimals
+	}
+	
 	/// Set the money symbol to use.  
 	/// Default: '$'
 	///
@@ -57,11 +461,46 @@ impl Options {
 	/// assert_eq!(m.to_string(), "£5.25");
 	/// ```
 	pub fn set_symbol(&mut self, symbol: char) -> bool {
-		if symbol.is_ascii_digit() {
-			false
-		} else {
-			self.symbol = symbol;
-			true
+		match Symbol::new(symbol.encode_utf8(&mut [0; 4])) {
+			Some(s) => {
+				self.symbol = s;
+				true
+			},
+			None => {
+				false
+			},
+		}
+	}
+	
+	/// Set a money symbol of one or more characters (e.g. "US$" or "CHF").  
+	/// Default: "$"
+	///
+	/// Symbols that are empty, longer than 16 bytes, or contain digits or whitespace are invalid,
+	/// and the function will return `false`.  
+	/// Otherwise, the function returns `true`.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// m.options().set_symbol_str("US$");
+	///
+	/// assert_eq!(m.to_string(), "US$5.25");
+	/// ```
+	pub fn set_symbol_str(&mut self, symbol: &str) -> bool {
+		if symbol.chars().any(char::is_whitespace) {
+			return false;
+		}
+		
+		match Symbol::new(symbol) {
+			Some(s) => {
+				self.symbol = s;
+				true
+			},
+			None => {
+				false
+			},
 		}
 	}
 	
@@ -103,7 +542,428 @@ impl Options {
 	///
 	/// assert_eq!(m.to_string(), "$5.25");
 	/// ```
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::NegativeView;
+	/// let mut m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	/// m.options().set_negative_view(NegativeView::ParenMinus);
+	///
+	/// assert_eq!(m.to_string(), "(-$5.25)");
+	/// ```
 	pub fn set_negative_view(&mut self, negative_view: NegativeView) {
 		self.negative_view = negative_view;
 	}
+	
+	/// Set whether positive amounts are prefixed with a plus sign.  
+	/// Default: false
+	///
+	/// Only applies when the negative view is `NegativeView::Minus`, so that credits and debits are always marked.
+	/// Zero is never signed.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// m.options().set_force_sign(true);
+	///
+	/// assert_eq!(m.to_string(), "+$5.25");
+	/// ```
+	pub fn set_force_sign(&mut self, force_sign: bool) {
+		self.force_sign = force_sign;
+	}
+	
+	/// Set the character separating dollars from cents.  
+	/// Default: '.'
+	///
+	/// Digits are considered invalid separators, and the function will return `false`.  
+	/// Otherwise, the function returns `true`.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// m.options().set_decimal_separator(',');
+	///
+	/// assert_eq!(m.to_string(), "$5,25");
+	/// ```
+	pub fn set_decimal_separator(&mut self, decimal_separator: char) -> bool {
+		if decimal_separator.is_ascii_digit() {
+			false
+		} else {
+			self.decimal_separator = decimal_separator;
+			true
+		}
+	}
+	
+	/// Set the currency the value is tagged with.  
+	/// Default: None
+	///
+	/// The currency does not change the symbol; use `set_symbol()` for that.
+	pub fn set_currency(&mut self, currency: Option<Currency>) {
+		self.currency = currency;
+	}
+	
+	/// Set whether the money symbol is placed before or after the amount.  
+	/// Default: Prefix
+	///
+	/// A suffix symbol is separated from the amount by a space (see `set_symbol_spacing()`), and a
+	/// minus sign trails the amount.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::SymbolPosition;
+	/// let mut m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	/// m.options().set_symbol('€');
+	/// m.options().set_symbol_position(SymbolPosition::Suffix);
+	///
+	/// assert_eq!(m.to_string(), "5.25- €");
+	/// ```
+	pub fn set_symbol_position(&mut self, symbol_position: SymbolPosition) {
+		self.symbol_position = symbol_position;
+	}
+	
+	/// Set whether the cents are omitted from the string for whole amounts.  
+	/// Default: false
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(5, 0, MoneySign::Positive).unwrap();
+	/// m.options().set_hide_zero_cents(true);
+	///
+	/// assert_eq!(m.to_string(), "$5");
+	/// ```
+	pub fn set_hide_zero_cents(&mut self, hide_zero_cents: bool) {
+		self.hide_zero_cents = hide_zero_cents;
+	}
+	
+	/// Set the number of minor digits shown in the string, independent of the stored cents.  
+	/// Default: None (two digits)
+	///
+	/// Extra digits are padded with zeros. Fewer digits round the amount half away from zero.
+	///
+	/// More than 18 digits is invalid, and the function will return `false`.  
+	/// Otherwise, the function returns `true`.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// m.options().set_minor_digits(Some(3));
+	///
+	/// assert_eq!(m.to_string(), "$5.250");
+	///
+	/// m.options().set_minor_digits(Some(1));
+	///
+	/// assert_eq!(m.to_string(), "$5.3");
+	/// assert!(!m.options().set_minor_digits(Some(19)));
+	/// ```
+	pub fn set_minor_digits(&mut self, minor_digits: Option<u32>) -> bool {
+		if minor_digits.is_some_and(|d| d > MAX_MINOR_DIGITS) {
+			false
+		} else {
+			self.minor_digits = minor_digits;
+			true
+		}
+	}
+	
+	/// Set the digit grouping applied to the dollars.  
+	/// Default: None
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::GroupingStyle;
+	/// let mut m = Money::new(1234567, 89, MoneySign::Positive).unwrap();
+	/// m.options().set_grouping(Some(GroupingStyle::Thousands));
+	///
+	/// assert_eq!(m.to_string(), "$1,234,567.89");
+	///
+	/// m.options().set_grouping(Some(GroupingStyle::Indian));
+	///
+	/// assert_eq!(m.to_string(), "$12,34,567.89");
+	/// ```
+	pub fn set_grouping(&mut self, grouping: Option<GroupingStyle>) {
+		self.grouping = grouping;
+	}
+	
+	/// Set the character separating digit groups.  
+	/// Default: ','
+	///
+	/// Digits are considered invalid separators, and the function will return `false`.  
+	/// Otherwise, the function returns `true`.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::GroupingStyle;
+	/// let mut m = Money::new(1234567, 89, MoneySign::Positive).unwrap();
+	/// m.options().set_grouping(Some(GroupingStyle::Thousands));
+	/// m.options().set_grouping_separator('.');
+	/// m.options().set_decimal_separator(',');
+	///
+	/// assert_eq!(m.to_string(), "$1.234.567,89");
+	/// ```
+	pub fn set_grouping_separator(&mut self, grouping_separator: char) -> bool {
+		if grouping_separator.is_ascii_digit() {
+			false
+		} else {
+			self.grouping_separator = grouping_separator;
+			true
+		}
+	}
+	
+	/// Set whether the string is wrapped in right-to-left marks (U+200F), for right-to-left locales.  
+	/// Default: false
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	/// m.options().set_rtl(true);
+	///
+	/// assert_eq!(m.to_string(), "\u{200F}-$5.25\u{200F}");
+	/// ```
+	pub fn set_rtl(&mut self, rtl: bool) {
+		self.rtl = rtl;
+	}
+	
+	/// Set a string to show in place of the amount when it's exactly zero, such as "—" or "FREE".  
+	/// Default: None
+	///
+	/// The string is shown as-is, so it won't parse back into a Money instance. The string is copied,
+	/// so it may be built at runtime (e.g. read from a translation table).
+	///
+	/// Strings longer than 32 bytes are invalid, and the function will return `false`.  
+	/// Otherwise, the function returns `true`.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(0, 0, MoneySign::Positive).unwrap();
+	/// m.options().set_zero_display(Some("—"));
+	///
+	/// assert_eq!(m.to_string(), "—");
+	///
+	/// let label = String::from("GRATIS");
+	/// m.options().set_zero_display(Some(&label));
+	///
+	/// assert_eq!(m.to_string(), "GRATIS");
+	/// ```
+	pub fn set_zero_display(&mut self, zero_display: Option<&str>) -> bool {
+		match zero_display.map(ZeroDisplay::copy_from) {
+			Some(None) => {
+				false
+			},
+			Some(z) => {
+				self.zero_display = z;
+				true
+			},
+			None => {
+				self.zero_display = None;
+				true
+			},
+		}
+	}
+	
+	/// Set whether the minor digits are shown as raised (superscript) digits, as on a price tag.  
+	/// Default: false
+	///
+	/// The decimal separator is omitted when the minor digits are raised.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(5, 99, MoneySign::Positive).unwrap();
+	/// m.options().set_raised_minor(true);
+	///
+	/// assert_eq!(m.to_string(), "$5⁹⁹");
+	/// ```
+	pub fn set_raised_minor(&mut self, raised_minor: bool) {
+		self.raised_minor = raised_minor;
+	}
+	
+	/// Set whether a space separates the symbol and the amount, independent of the symbol position.  
+	/// Default: None (a space only separates a suffix symbol)
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::SymbolPosition;
+	/// let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// m.options().set_symbol_spacing(Some(true));
+	///
+	/// assert_eq!(m.to_string(), "$ 5.25");
+	///
+	/// m.options().set_symbol('€');
+	/// m.options().set_symbol_position(SymbolPosition::Suffix);
+	/// m.options().set_symbol_spacing(Some(false));
+	///
+	/// assert_eq!(m.to_string(), "5.25€");
+	/// ```
+	pub fn set_symbol_spacing(&mut self, symbol_spacing: Option<bool>) {
+		self.symbol_spacing = symbol_spacing;
+	}
+	
+	/// Set whether the space separating the symbol and the amount is a non-breaking space (U+00A0),
+	/// so that the symbol doesn't wrap away from the amount (see `set_symbol_spacing()`).  
+	/// Default: false
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::SymbolPosition;
+	/// let mut m = Money::new(5, 25, MoneySign::Positive).unwrap();
+	/// m.options().set_symbol('€');
+	/// m.options().set_symbol_position(SymbolPosition::Suffix);
+	/// m.options().set_nbsp(true);
+	///
+	/// assert_eq!(m.to_string(), "5.25\u{00A0}€");
+	/// ```
+	pub fn set_nbsp(&mut self, nbsp: bool) {
+		self.nbsp = nbsp;
+	}
+	
+	/// Set whether the sign marker is placed before or after the symbol. With a suffix symbol, the
+	/// marker then follows the symbol (e.g. "5.25 €-").  
+	/// Default: BeforeSymbol
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::options::NegativeSignPosition;
+	/// let mut m = Money::new(5, 25, MoneySign::Negative).unwrap();
+	///
+	/// assert_eq!(m.to_string(), "-$5.25");
+	///
+	/// m.options().set_negative_sign_position(NegativeSignPosition::AfterSymbol);
+	///
+	/// assert_eq!(m.to_string(), "$-5.25");
+	/// ```
+	pub fn set_negative_sign_position(&mut self, negative_sign_position: NegativeSignPosition) {
+		self.negative_sign_position = negative_sign_position;
+	}
+	
+	/// Set whether the minor digits are grouped in threes from the decimal separator, for amounts
+	/// shown with more than three minor digits (see `set_minor_digits()`).  
+	/// Default: false
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(1, 23, MoneySign::Positive).unwrap();
+	/// m.options().set_minor_digits(Some(8));
+	/// m.options().set_group_minor(true);
+	///
+	/// assert_eq!(m.to_string(), "$1.230 000 00");
+	/// ```
+	pub fn set_group_minor(&mut self, group_minor: bool) {
+		self.group_minor = group_minor;
+	}
+	
+	/// Set the character separating groups of minor digits.  
+	/// Default: ' '
+	///
+	/// Digits are considered invalid separators, and the function will return `false`.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(1, 23, MoneySign::Positive).unwrap();
+	/// m.options().set_minor_digits(Some(6));
+	/// m.options().set_group_minor(true);
+	///
+	/// assert!(m.options().set_minor_grouping_separator('_'));
+	/// assert_eq!(m.to_string(), "$1.230_000");
+	/// assert!(!m.options().set_minor_grouping_separator('7'));
+	/// ```
+	pub fn set_minor_grouping_separator(&mut self, minor_grouping_separator: char) -> bool {
+		if minor_grouping_separator.is_ascii_digit() {
+			false
+		} else {
+			self.minor_grouping_separator = minor_grouping_separator;
+			true
+		}
+	}
+	
+	/// Set whether amounts with zero dollars are shown as a number of cents followed by the minor
+	/// symbol, in place of the symbol and decimal separator (e.g. "99¢" rather than "$0.99").  
+	/// Default: false
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(0, 99, MoneySign::Positive).unwrap();
+	/// m.options().set_sub_dollar_as_minor(true);
+	///
+	/// assert_eq!(m.to_string(), "99¢");
+	/// ```
+	pub fn set_sub_dollar_as_minor(&mut self, sub_dollar_as_minor: bool) {
+		self.sub_dollar_as_minor = sub_dollar_as_minor;
+	}
+	
+	/// Set the symbol shown after amounts in minor units (see `set_sub_dollar_as_minor()`).  
+	/// Default: '¢'
+	///
+	/// Digits are considered invalid symbols, and the function will return `false`.
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(0, 50, MoneySign::Positive).unwrap();
+	/// m.options().set_sub_dollar_as_minor(true);
+	///
+	/// assert!(m.options().set_minor_symbol('p'));
+	/// assert_eq!(m.to_string(), "50p");
+	/// assert!(!m.options().set_minor_symbol('7'));
+	/// ```
+	pub fn set_minor_symbol(&mut self, minor_symbol: char) -> bool {
+		if minor_symbol.is_ascii_digit() {
+			false
+		} else {
+			self.minor_symbol = minor_symbol;
+			true
+		}
+	}
+	
+	/// Set whether two minor digits are always shown, as on some registers, overriding the
+	/// `minor_digits` and `hide_zero_cents` options.  
+	/// Default: false
+	///
+	/// Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// let mut m = Money::new(500, 0, MoneySign::Positive).unwrap();
+	/// m.options().set_symbol('¥');
+	/// m.options().set_minor_digits(Some(0));
+	///
+	/// assert_eq!(m.to_string(), "¥500");
+	///
+	/// m.options().set_force_two_decimals(true);
+	///
+	/// assert_eq!(m.to_string(), "¥500.00");
+	/// ```
+	pub fn set_force_two_decimals(&mut self, force_two_decimals: bool) {
+		self.force_two_decimals = force_two_decimals;
+	}
 }
\ No newline at end of file
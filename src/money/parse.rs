@@ -0,0 +1,412 @@
+use super::{Money, MoneySign, ParseMoneyError};
+use super::parse_options::ParseOptions;
+use super::options::{NegativeView, NegativeSignPosition, SymbolPosition, GroupingStyle};
+
+const DECIMAL_SEPARATORS: [char; 2] = ['.', ','];
+
+/* spaces, including non-breaking spaces, and Swiss apostrophes may also separate digit groups */
+const GROUPING_SEPARATORS: [char; 7] = [
+	'.',
+	',',
+	'\'',
+	'\u{2019}',  // right single quotation mark
+	' ',
+	'\u{00A0}',  // no-break space
+	'\u{202F}',  // narrow no-break space
+];
+
+const RTL_MARK: char = '\u{200F}';
+const NBSP: char = '\u{00A0}';
+const MINUS_SIGN: char = '\u{2212}';
+
+const INVISIBLE_CHARS: [char; 7] = [
+	'\u{FEFF}',  // byte order mark
+	'\u{200E}',  // left-to-right mark
+	'\u{200F}',  // right-to-left mark
+	'\u{200B}',  // zero-width space
+	'\u{200C}',  // zero-width non-joiner
+	'\u{200D}',  // zero-width joiner
+	'\u{2060}',  // word joiner
+];
+
+/* removes surrounding whitespace and invisible characters often left behind by copy and paste */
+pub(super) fn trim_input(s: &str) -> &str {
+	s.trim_matches(|c: char| c.is_whitespace() || INVISIBLE_CHARS.contains(&c))
+}
+
+/*
+ * Parses the forms produced by 'Display': a number, surrounded by an optional sign marker and
+ * symbol before or after it, optionally wrapped in parentheses. The options of the result are set
+ * so that it displays the same string. The forms and symbols accepted are set by 'opts'.
+ */
+pub(super) fn parse_money(s: &str, opts: &ParseOptions) -> Result<Money, ParseMoneyError> {
+	/* the Unicode minus sign, found in typeset sources, is read as a hyphen-minus */
+	let normalized = s.replace(MINUS_SIGN, "-");
+	let r = trim_input(&normalized);
+	let is_rtl = s.trim().starts_with(RTL_MARK);
+	
+	// check for an accounting credit or debit suffix in place of a sign
+	let (r, entry) = if opts.allow_entry_suffix {
+		split_entry_suffix(r)
+	} else {
+		(r, None)
+	};
+	
+	// break apart string
+	let first = match r.find(|c: char| c.is_ascii_digit()) {
+		Some(i) => { i },
+		None => { return Err(ParseMoneyError::Number); },
+	};
+	
+	let last = r.rfind(|c: char| c.is_ascii_digit()).unwrap();
+	let number = &r[first..=last];
+	let mut prefix = r[..first].to_string();
+	let mut suffix = &r[last + 1..];
+	
+	// check for parentheses, which may follow a leading symbol
+	let is_paren = match prefix.find('(') {
+		Some(_) if !opts.allow_parentheses => {
+			return Err(ParseMoneyError::Parentheses);
+		},
+		Some(i) => {
+			/* a sign marker belongs inside the parentheses */
+			if prefix[..i].contains(['-', '+']) {
+				return Err(ParseMoneyError::Parentheses);
+			}
+			
+			suffix = match suffix.strip_suffix(')') {
+				Some(rest) => { rest },
+				None => { return Err(ParseMoneyError::Parentheses); },
+			};
+			
+			let _ = prefix.remove(i);
+			true
+		},
+		None => {
+			false
+		},
+	};
+	
+	// check for sign and symbol on either side
+	let prefix_affix = split_prefix(&prefix)?;
+	let suffix_affix = split_suffix(suffix)?;
+	let Affix { sign: prefix_sign, symbol: prefix_symbol, spaced: prefix_spaced, .. } = prefix_affix;
+	let Affix { sign: suffix_sign, symbol: suffix_symbol, spaced: suffix_spaced, .. } = suffix_affix;
+	
+	if suffix_sign.is_some() && !opts.allow_suffix_sign {
+		return Err(ParseMoneyError::SuffixSign);
+	}
+	
+	let marker = match (prefix_sign, suffix_sign) {
+		(Some(_), Some(_)) => { return Err(ParseMoneyError::Sign); },
+		(Some(c), None) | (None, Some(c)) => {
+			if is_paren && c != '-' {
+				return Err(ParseMoneyError::Sign);
+			}
+			Some(c)
+		},
+		(None, None) => { None },
+	};
+	
+	let symbol = match (prefix_symbol, suffix_symbol) {
+		(Some(_), Some(_)) => { return Err(ParseMoneyError::Symbol); },
+		(Some(sym), None) | (None, Some(sym)) => {
+			if !(is_accepted_symbol(sym, &opts.accepted_symbols) || (opts.allow_codes && is_code(sym))) {
+				return Err(ParseMoneyError::Symbol);
+			}
+			Some(sym)
+		},
+		(None, None) => { None },
+	};
+	
+	let (d, c, decimal, grouping) = parse_number(number, opts.decimal_separator, opts.allow_ambiguous_grouping)?;
+	
+	if entry.is_some() && (is_paren || marker.is_some()) {
+		return Err(ParseMoneyError::Sign);
+	}
+	
+	let sign = if is_paren || marker == Some('-') || entry == Some(MoneySign::Negative) {
+		MoneySign::Negative
+	} else {
+		MoneySign::Positive
+	};
+	
+	let mut m = Money::new(d, c, sign).unwrap();
+	let options = m.options();
+	
+	if is_paren && marker.is_some() {
+		options.set_negative_view(NegativeView::ParenMinus);
+	} else if is_paren {
+		options.set_negative_view(NegativeView::Paren);
+	}
+	
+	if marker == Some('+') {
+		options.set_force_sign(true);
+	}
+	
+	if prefix_affix.sign_after_symbol || suffix_affix.sign_after_symbol {
+		options.set_negative_sign_position(NegativeSignPosition::AfterSymbol);
+	}
+	
+	if let Some(sym) = symbol {
+		if !options.set_symbol_str(sym) {
+			return Err(ParseMoneyError::Symbol);
+		}
+	} else {
+		options.set_show_symbol(false);
+	}
+	
+	if suffix_sign.is_some() || suffix_symbol.is_some() {
+		options.set_symbol_position(SymbolPosition::Suffix);
+	}
+	
+	/* a non-breaking space between the symbol and the number is kept */
+	if (prefix_spaced && prefix.contains(NBSP)) || (suffix_symbol.is_some() && suffix_spaced && suffix.contains(NBSP)) {
+		options.set_nbsp(true);
+	}
+	
+	/* the spacing is only set when it differs from the default for the position */
+	if prefix_spaced {
+		options.set_symbol_spacing(Some(true));
+	} else if suffix_symbol.is_some() && !suffix_spaced {
+		options.set_symbol_spacing(Some(false));
+	}
+	
+	if let Some(sep) = decimal {
+		options.set_decimal_separator(sep);
+	} else {
+		options.set_hide_zero_cents(true);
+	}
+	
+	if let Some((style, sep)) = grouping {
+		options.set_grouping(Some(style));
+		options.set_grouping_separator(sep);
+		
+		/* without cents the decimal separator is unknown, so keep it distinct from the grouping */
+		if sep == options.decimal_separator() {
+			options.set_decimal_separator(if sep == '.' { ',' } else { '.' });
+		}
+	}
+	
+	if is_rtl {
+		options.set_rtl(true);
+	}
+	
+	Ok(m)
+}
+
+/* splits a trailing " CR" (credit) or " DR" (debit), in any case, returning the sign it indicates */
+fn split_entry_suffix(s: &str) -> (&str, Option<MoneySign>) {
+	let Some(i) = s.len().checked_sub(2).filter(|i| s.is_char_boundary(*i)) else {
+		return (s, None);
+	};
+	
+	let (rest, entry) = s.split_at(i);
+	
+	if !rest.ends_with(char::is_whitespace) {
+		return (s, None);
+	}
+	
+	match entry.to_ascii_uppercase().as_str() {
+		"CR" => { (rest.trim_end(), Some(MoneySign::Positive)) },
+		"DR" => { (rest.trim_end(), Some(MoneySign::Negative)) },
+		_ => { (s, None) },
+	}
+}
+
+fn is_symbol_char(c: char) -> bool {
+	!c.is_ascii_digit() && !c.is_whitespace() && !"+-(),.".contains(c) && !INVISIBLE_CHARS.contains(&c)
+}
+
+/* combining diacritical marks, which may modify a symbol character */
+fn is_combining_mark(c: char) -> bool {
+	matches!(c, '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}' | '\u{FE20}'..='\u{FE2F}')
+}
+
+/*
+ * a symbol run (e.g. "US$") is accepted if it contains exactly one accepted symbol character, and
+ * the rest are letters or combining marks
+ */
+fn is_accepted_symbol(symbol: &str, accepted: &[char]) -> bool {
+	symbol.chars().filter(|c| accepted.contains(c)).count() == 1 &&
+		symbol.chars().all(|c| accepted.contains(&c) || c.is_alphabetic() || is_combining_mark(c))
+}
+
+/* a currency code or crypto ticker used as the symbol (e.g. "CHF" or "BTC") */
+fn is_code(symbol: &str) -> bool {
+	(3..=5).contains(&symbol.len()) && symbol.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/* the sign marker and symbol found on one side of the number */
+struct Affix<'a> {
+	sign: Option<char>,
+	symbol: Option<&'a str>,
+	spaced: bool,
+	sign_after_symbol: bool,
+}
+
+/*
+ * splits a sign marker and a run of symbol characters, in either order, preceding the number, and
+ * whether a single space follows the symbol
+ */
+fn split_prefix(prefix: &str) -> Result<Affix<'_>, ParseMoneyError> {
+	let mut sign = None;
+	let mut symbol: Option<(usize, usize)> = None;
+	let mut spaced = false;
+	
+	for (i, c) in prefix.char_indices() {
+		let follows_symbol = matches!(symbol, Some((_, end)) if end == i);
+		
+		if (c == '-' || c == '+') && sign.is_none() {
+			sign = Some(c);
+		} else if is_symbol_char(c) && (symbol.is_none() || follows_symbol) && !spaced {
+			let start = symbol.map_or(i, |(start, _)| start);
+			symbol = Some((start, i + c.len_utf8()));
+		} else if c.is_whitespace() && follows_symbol && !spaced {
+			spaced = true;
+		} else if c == '-' || c == '+' {
+			return Err(ParseMoneyError::Sign);
+		} else {
+			return Err(ParseMoneyError::Symbol);
+		}
+	}
+	
+	let sign_after_symbol = match (sign, symbol) {
+		(Some(_), Some((start, _))) => { !prefix[..start].contains(['-', '+']) },
+		_ => { false },
+	};
+	
+	Ok(Affix { sign, symbol: symbol.map(|(start, end)| &prefix[start..end]), spaced, sign_after_symbol })
+}
+
+/*
+ * splits a sign marker, then a run of symbol characters optionally separated by whitespace,
+ * following the number, and whether the whitespace was present; the sign marker may instead
+ * follow the symbol
+ */
+fn split_suffix(suffix: &str) -> Result<Affix<'_>, ParseMoneyError> {
+	let mut r = suffix;
+	let mut sign = None;
+	let mut sign_after_symbol = false;
+	
+	if let Some(c) = r.chars().next().filter(|c| *c == '-' || *c == '+') {
+		sign = Some(c);
+		r = &r[1..];
+	} else if let Some(c) = r.chars().next_back().filter(|c| *c == '-' || *c == '+') {
+		sign = Some(c);
+		sign_after_symbol = true;
+		r = &r[..r.len() - 1];
+		
+		/* the sign marker must touch the symbol */
+		if r.trim().is_empty() || r.ends_with(char::is_whitespace) {
+			return Err(ParseMoneyError::Sign);
+		}
+	}
+	
+	if r.is_empty() {
+		return Ok(Affix { sign, symbol: None, spaced: false, sign_after_symbol });
+	}
+	
+	let trimmed = r.trim_start();
+	let spaced = trimmed.len() < r.len();
+	
+	/* a letter touching the number is more likely a typo than a symbol */
+	match trimmed.chars().next() {
+		Some(c) if (spaced || !c.is_alphanumeric()) && trimmed.chars().all(is_symbol_char) => {
+			Ok(Affix { sign, symbol: Some(trimmed), spaced, sign_after_symbol })
+		},
+		_ => {
+			Err(ParseMoneyError::Symbol)
+		},
+	}
+}
+
+/*
+ * splits the number into dollars and cents, returning the decimal and grouping separators found;
+ * the decimal separator is inferred unless 'expected_decimal' is given, but a lone '.' followed
+ * by three digits (e.g. "1.234") is only inferred as grouping if 'allow_ambiguous'
+ */
+#[allow(clippy::type_complexity)]
+fn parse_number(number: &str, expected_decimal: Option<char>, allow_ambiguous: bool) -> Result<(u64, u8, Option<char>, Option<(GroupingStyle, char)>), ParseMoneyError> {
+	// the last separator is the decimal separator if it is followed by one or two digits
+	let (whole, decimal, fraction) = match number.rfind(|c: char| !c.is_ascii_digit()) {
+		Some(i) => {
+			let sep = number[i..].chars().next().unwrap();
+			let tail = &number[i + sep.len_utf8()..];
+			let is_decimal = match expected_decimal {
+				Some(d) => { sep == d },
+				None => { DECIMAL_SEPARATORS.contains(&sep) },
+			};
+			
+			let is_lone = number.chars().filter(|c| !c.is_ascii_digit()).count() == 1;
+			let is_ambiguous = sep == '.' && is_lone && tail.len() == 3 && !allow_ambiguous;
+			
+			if is_decimal && (tail.len() == 1 || tail.len() == 2) {
+				(&number[..i], Some(sep), tail)
+			} else if is_decimal && expected_decimal.is_some() {
+				return Err(ParseMoneyError::Number);
+			} else if is_decimal && is_ambiguous {
+				return Err(ParseMoneyError::Ambiguous);
+			} else {
+				(number, None, "")
+			}
+		},
+		None => {
+			(number, None, "")
+		},
+	};
+	
+	// any other separators are grouping separators
+	let grouping = match whole.chars().find(|c| !c.is_ascii_digit()) {
+		Some(sep) => {
+			if !GROUPING_SEPARATORS.contains(&sep) ||
+				Some(sep) == decimal ||
+				!whole.chars().all(|c| c.is_ascii_digit() || c == sep) {
+				return Err(ParseMoneyError::Number);
+			}
+			
+			grouping_style(whole, sep)?.map(|style| (style, sep))
+		},
+		None => {
+			None
+		},
+	};
+	
+	let digits: String = whole.chars().filter(|c| c.is_ascii_digit()).collect();
+	
+	let d = match digits.parse::<u64>() {
+		Ok(r) => { r },
+		Err(_) => { return Err(ParseMoneyError::Number); },
+	};
+	
+	let c = match fraction.len() {
+		0 => { 0 },
+		1 => { fraction.parse::<u8>().unwrap() * 10 },
+		_ => { fraction.parse::<u8>().unwrap() },
+	};
+	
+	Ok((d, c, decimal, grouping))
+}
+
+/* returns the grouping style of 'digits', or an error if the groups are malformed */
+fn grouping_style(digits: &str, separator: char) -> Result<Option<GroupingStyle>, ParseMoneyError> {
+	let groups: Vec<_> = digits.split(separator).collect();
+	
+	if groups.len() == 1 {
+		return Ok(None);
+	}
+	
+	let first = groups[0].len();
+	let last = groups[groups.len() - 1].len();
+	let middle = &groups[1..groups.len() - 1];
+	
+	if !(1..=3).contains(&first) || last != 3 {
+		Err(ParseMoneyError::Number)
+	} else if middle.iter().all(|g| g.len() == 3) {
+		Ok(Some(GroupingStyle::Thousands))
+	} else if first <= 2 && middle.iter().all(|g| g.len() == 2) {
+		Ok(Some(GroupingStyle::Indian))
+	} else {
+		Err(ParseMoneyError::Number)
+	}
+}
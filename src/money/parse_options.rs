@@ -0,0 +1,188 @@
+use super::DEFAULT_ACCEPTED_SYMBOLS;
+
+/// Configures how `Money::parse_with()` reads a string. The defaults match `Money::from_str()`.
+///
+/// # Example
+///
+/// ```
+/// # use nmoney::{Money, ParseOptions};
+/// let opts = ParseOptions::new()
+///     .accepted_symbols(&['#'])
+///     .decimal_separator(',')
+///     .allow_parentheses(false);
+///
+/// assert_eq!(Money::parse_with("#1.234", &opts).unwrap(), Money::from_cents(123400));
+/// assert!(Money::parse_with("(#1.234)", &opts).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseOptions {
+	pub(super) accepted_symbols: Vec<char>,
+	pub(super) decimal_separator: Option<char>,
+	pub(super) allow_parentheses: bool,
+	pub(super) allow_suffix_sign: bool,
+	pub(super) allow_entry_suffix: bool,
+	pub(super) allow_ambiguous_grouping: bool,
+	pub(super) allow_codes: bool,
+}
+
+impl ParseOptions {
+	/// Creates the default parse options.
+	pub fn new() -> Self {
+		Self {
+			accepted_symbols: DEFAULT_ACCEPTED_SYMBOLS.to_vec(),
+			decimal_separator: None,
+			allow_parentheses: true,
+			allow_suffix_sign: true,
+			allow_entry_suffix: true,
+			allow_ambiguous_grouping: false,
+			allow_codes: true,
+		}
+	}
+	
+	/// Set the symbols recognized before or after the number.  
+	/// Default: `DEFAULT_ACCEPTED_SYMBOLS`
+	pub fn accepted_symbols(mut self, accepted_symbols: &[char]) -> Self {
+		self.accepted_symbols = accepted_symbols.to_vec();
+		self
+	}
+	
+	/// Set the decimal separator, rather than inferring it. Any other separator is then grouping.  
+	/// Default: None (inferred)
+	pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+		self.decimal_separator = Some(decimal_separator);
+		self
+	}
+	
+	/// Set whether parentheses are accepted to indicate a negative amount.  
+	/// Default: true
+	pub fn allow_parentheses(mut self, allow_parentheses: bool) -> Self {
+		self.allow_parentheses = allow_parentheses;
+		self
+	}
+	
+	/// Set whether a sign marker is accepted after the number, as in `"5.25- €"`.  
+	/// Default: true
+	pub fn allow_suffix_sign(mut self, allow_suffix_sign: bool) -> Self {
+		self.allow_suffix_sign = allow_suffix_sign;
+		self
+	}
+	
+	/// Set whether a `" CR"` or `" DR"` accounting suffix is accepted in place of a sign.  
+	/// Default: true
+	pub fn allow_entry_suffix(mut self, allow_entry_suffix: bool) -> Self {
+		self.allow_entry_suffix = allow_entry_suffix;
+		self
+	}
+	
+	/// Set whether a code of three to five capital letters, as in `"1.23 BTC"`, is accepted as the symbol.  
+	/// Default: true
+	pub fn allow_codes(mut self, allow_codes: bool) -> Self {
+		self.allow_codes = allow_codes;
+		self
+	}
+	
+	/// Set whether a lone '.' followed by three digits, as in `"1.234"`, is read as grouping.
+	/// Otherwise, the '.' is the decimal separator (unless it is set), and the amount is rejected.  
+	/// Default: false
+	pub fn allow_ambiguous_grouping(mut self, allow_ambiguous_grouping: bool) -> Self {
+		self.allow_ambiguous_grouping = allow_ambiguous_grouping;
+		self
+	}
+}
+
+impl Default for ParseOptions {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Money, ParseMoneyError};
+	
+	#[test]
+	fn defaults_match_from_str() {
+		let opts = ParseOptions::default();
+		
+		for s in ["$1,234.56", "(5.25 €)", "5.25-", "5.00 DR", "1.234,5", "#5"] {
+			let m1 = Money::parse_with(s, &opts);
+			let m2 = Money::from_str(s);
+			
+			match (m1, m2) {
+				(Ok(m1), Ok(m2)) => { assert!(m1.eq_exact(&m2)); },
+				(m1, m2) => { assert!(m1.is_err() && m2.is_err()); },
+			}
+		}
+	}
+	
+	#[test]
+	fn decimal_separator() {
+		let opts = ParseOptions::new().decimal_separator(',');
+		
+		assert_eq!(Money::parse_with("1.234", &opts).unwrap(), Money::from_cents(123400));
+		assert_eq!(Money::parse_with("1,5", &opts).unwrap(), Money::from_cents(150));
+		assert_eq!(Money::parse_with("€1.234,56", &opts).unwrap().to_string(), "€1.234,56");
+		assert_eq!(Money::parse_with("1,234.56", &opts), Err(ParseMoneyError::Number));
+		assert_eq!(Money::parse_with("1,234", &opts), Err(ParseMoneyError::Number));
+	}
+	
+	#[test]
+	fn disallowed_forms() {
+		let opts = ParseOptions::new()
+			.allow_parentheses(false)
+			.allow_suffix_sign(false)
+			.allow_entry_suffix(false)
+			.allow_codes(false);
+		
+		assert_eq!(Money::parse_with("($5.25)", &opts), Err(ParseMoneyError::Parentheses));
+		assert_eq!(Money::parse_with("1.23 BTC", &opts), Err(ParseMoneyError::Symbol));
+		assert_eq!(Money::parse_with("5.25- €", &opts), Err(ParseMoneyError::SuffixSign));
+		assert_eq!(Money::parse_with("5.25 DR", &opts), Err(ParseMoneyError::Symbol));
+		assert_eq!(Money::parse_with("-5.25 €", &opts).unwrap(), Money::from_cents(-525));
+	}
+	
+	#[test]
+	fn combined() {
+		let opts = ParseOptions::new()
+			.accepted_symbols(&['R'])
+			.decimal_separator('.')
+			.allow_suffix_sign(false);
+		
+		assert_eq!(Money::parse_with("R 1 234.5", &opts).unwrap(), Money::from_cents(123450));
+		assert_eq!(Money::parse_with("1.23 R CR", &opts).unwrap(), Money::from_cents(123));
+		assert_eq!(Money::parse_with("1.234 R", &opts), Err(ParseMoneyError::Number));
+		assert_eq!(Money::parse_with("$1.23", &opts), Err(ParseMoneyError::Symbol));
+		assert_eq!(Money::parse_with("1.23- R", &opts), Err(ParseMoneyError::SuffixSign));
+	}
+	
+	#[test]
+	fn ambiguous_grouping() {
+		let opts = ParseOptions::new().allow_ambiguous_grouping(true);
+		
+		assert_eq!(Money::parse_with("5.123", &ParseOptions::new()), Err(ParseMoneyError::Ambiguous));
+		assert_eq!(Money::parse_with("$1,234", &ParseOptions::new()).unwrap(), Money::from_dollars(1234));
+		assert_eq!(Money::parse_with("5.123", &opts).unwrap(), Money::from_cents(512300));
+		assert_eq!(Money::parse_with("1.234.567", &ParseOptions::new()).unwrap(), Money::from_dollars(1234567));
+		assert_eq!(Money::parse_with("1 234", &ParseOptions::new()).unwrap(), Money::from_dollars(1234));
+	}
+	
+	#[test]
+	fn errors() {
+		let opts = ParseOptions::default();
+		
+		assert_eq!(Money::parse_with("", &opts), Err(ParseMoneyError::Number));
+		assert_eq!(Money::parse_with("1,23,4", &opts), Err(ParseMoneyError::Number));
+		assert_eq!(Money::parse_with("99999999999999999999", &opts), Err(ParseMoneyError::Number));
+		assert_eq!(Money::parse_with("--5", &opts), Err(ParseMoneyError::Sign));
+		assert_eq!(Money::parse_with("-5-", &opts), Err(ParseMoneyError::Sign));
+		assert_eq!(Money::parse_with("(+5)", &opts), Err(ParseMoneyError::Sign));
+		assert_eq!(Money::parse_with("-5 CR", &opts), Err(ParseMoneyError::Sign));
+		assert_eq!(Money::parse_with("$5 €", &opts), Err(ParseMoneyError::Symbol));
+		assert_eq!(Money::parse_with("5x", &opts), Err(ParseMoneyError::Symbol));
+		assert_eq!(Money::parse_with("($5", &opts), Err(ParseMoneyError::Parentheses));
+		assert_eq!(Money::parse_with("-($5)", &opts), Err(ParseMoneyError::Parentheses));
+		assert_eq!(Money::from_str("--5").unwrap_err().to_string(), "invalid money string");
+		assert_eq!(ParseMoneyError::SuffixSign.to_string(), "sign marker after the number not allowed");
+	}
+}
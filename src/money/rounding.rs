@@ -0,0 +1,169 @@
+/// Rounding strategies applied when an amount cannot be represented exactly in cents.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+	/// Rounds toward zero.
+	Truncate,
+	/// Rounds toward negative infinity.
+	Floor,
+	/// Rounds toward positive infinity.
+	Ceiling,
+	/// Rounds to the nearest value, with halves rounded toward positive infinity.
+	HalfUp,
+	/// Rounds to the nearest value, with halves rounded to the even neighbor (banker's rounding).
+	HalfEven,
+	/// Rounds to the nearest value, with halves rounded away from zero (e.g. -2.5 to -3).
+	HalfAwayFromZero,
+}
+
+impl RoundingMode {
+	/* divides 'n' by 'd' (which must be non-zero), rounding the quotient with this mode */
+	pub(crate) fn divide(self, mut n: i128, mut d: i128) -> i128 {
+		if d < 0 {
+			n = -n;
+			d = -d;
+		}
+		
+		let q = n.div_euclid(d);  // rounded toward negative infinity
+		let r = n.rem_euclid(d);
+		
+		if r == 0 {
+			return q;
+		}
+		
+		match self {
+			RoundingMode::Truncate => { if n < 0 { q + 1 } else { q } },
+			RoundingMode::Floor => { q },
+			RoundingMode::Ceiling => { q + 1 },
+			RoundingMode::HalfUp => { if r >= d - r { q + 1 } else { q } },
+			RoundingMode::HalfEven => {
+				if r > d - r || (r == d - r && q % 2 != 0) {
+					q + 1
+				} else {
+					q
+				}
+			},
+			RoundingMode::HalfAwayFromZero => {
+				if r > d - r || (r == d - r && n > 0) {
+					q + 1
+				} else {
+					q
+				}
+			},
+		}
+	}
+	
+	/* rounds 'x' to a whole number with this mode */
+	pub(crate) fn round_f64(self, x: f64) -> f64 {
+		let f = x.floor();
+		let diff = x - f;
+		
+		match self {
+			RoundingMode::Truncate => { x.trunc() },
+			RoundingMode::Floor => { f },
+			RoundingMode::Ceiling => { x.ceil() },
+			RoundingMode::HalfUp => { if diff >= 0.5 { f + 1.0 } else { f } },
+			RoundingMode::HalfEven => {
+				if diff > 0.5 || (diff == 0.5 && f % 2.0 != 0.0) {
+					f + 1.0
+				} else {
+					f
+				}
+			},
+			RoundingMode::HalfAwayFromZero => { x.round() },
+		}
+	}
+}
+
+/// A rounding strategy, for rounding beyond the built-in `RoundingMode` variants.
+///
+/// # Example
+///
+/// ```
+/// # use nmoney::{Money, MoneySign};
+/// # use nmoney::money::rounding::Rounder;
+/// /* rounds halves to the odd neighbor */
+/// struct HalfOdd;
+///
+/// impl Rounder for HalfOdd {
+///     fn round(&self, scaled: i128, divisor: i128) -> i128 {
+///         let q = scaled.div_euclid(divisor);
+///         let r = scaled.rem_euclid(divisor);
+///
+///         if r > divisor - r || (r == divisor - r && q % 2 == 0) { q + 1 } else { q }
+///     }
+/// }
+///
+/// let m = Money::new(0, 5, MoneySign::Positive).unwrap();
+///
+/// assert_eq!(m.mul_ratio_with(1, 2, &HalfOdd).unwrap(), Money::from_cents(3));
+/// ```
+pub trait Rounder {
+	/// Returns `scaled / divisor` rounded to a whole number, where `divisor` is positive.
+	fn round(&self, scaled: i128, divisor: i128) -> i128;
+}
+
+impl Rounder for RoundingMode {
+	fn round(&self, scaled: i128, divisor: i128) -> i128 {
+		self.divide(scaled, divisor)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+	
+	#[test]
+	fn divide_exact() {
+		assert_eq!(RoundingMode::Truncate.divide(-10, 5), -2);
+		assert_eq!(RoundingMode::HalfEven.divide(10, -5), -2);
+	}
+	
+	#[test]
+	fn divide_positive() {
+		assert_eq!(RoundingMode::Truncate.divide(7, 2), 3);
+		assert_eq!(RoundingMode::Floor.divide(7, 2), 3);
+		assert_eq!(RoundingMode::Ceiling.divide(7, 2), 4);
+		assert_eq!(RoundingMode::HalfUp.divide(7, 2), 4);
+		assert_eq!(RoundingMode::HalfEven.divide(7, 2), 4);
+		assert_eq!(RoundingMode::HalfEven.divide(5, 2), 2);
+		assert_eq!(RoundingMode::HalfUp.divide(7, 3), 2);
+		assert_eq!(RoundingMode::HalfAwayFromZero.divide(7, 2), 4);
+		assert_eq!(RoundingMode::HalfAwayFromZero.divide(7, 3), 2);
+	}
+	
+	#[test]
+	fn divide_negative() {
+		assert_eq!(RoundingMode::Truncate.divide(-7, 2), -3);
+		assert_eq!(RoundingMode::Floor.divide(-7, 2), -4);
+		assert_eq!(RoundingMode::Ceiling.divide(-7, 2), -3);
+		assert_eq!(RoundingMode::HalfUp.divide(-7, 2), -3);
+		assert_eq!(RoundingMode::HalfEven.divide(-7, 2), -4);
+		assert_eq!(RoundingMode::HalfEven.divide(-5, 2), -2);
+		assert_eq!(RoundingMode::HalfUp.divide(-8, 3), -3);
+		assert_eq!(RoundingMode::HalfAwayFromZero.divide(-7, 2), -4);
+		assert_eq!(RoundingMode::HalfAwayFromZero.divide(7, -2), -4);
+		assert_eq!(RoundingMode::HalfAwayFromZero.divide(-8, 3), -3);
+	}
+	
+	#[test]
+	fn round_f64() {
+		assert_eq!(RoundingMode::Truncate.round_f64(-2.5), -2.0);
+		assert_eq!(RoundingMode::Floor.round_f64(-2.5), -3.0);
+		assert_eq!(RoundingMode::Ceiling.round_f64(2.1), 3.0);
+		assert_eq!(RoundingMode::HalfUp.round_f64(-2.5), -2.0);
+		assert_eq!(RoundingMode::HalfUp.round_f64(2.5), 3.0);
+		assert_eq!(RoundingMode::HalfEven.round_f64(2.5), 2.0);
+		assert_eq!(RoundingMode::HalfEven.round_f64(-3.5), -4.0);
+		assert_eq!(RoundingMode::HalfAwayFromZero.round_f64(-2.5), -3.0);
+		assert_eq!(RoundingMode::HalfAwayFromZero.round_f64(2.5), 3.0);
+		assert_eq!(RoundingMode::HalfAwayFromZero.round_f64(-2.4), -2.0);
+	}
+	
+	#[test]
+	fn rounder_matches_divide() {
+		let rounder: &dyn Rounder = &RoundingMode::HalfEven;
+		
+		assert_eq!(rounder.round(5, 2), RoundingMode::HalfEven.divide(5, 2));
+		assert_eq!(rounder.round(-7, 2), RoundingMode::HalfEven.divide(-7, 2));
+	}
+}
\ No newline at end of file
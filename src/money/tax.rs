@@ -0,0 +1,152 @@
+use super::{Money, MoneyError, convert_f64_to_money, convert_money_to_wide, convert_wide_to_money};
+use super::rounding::RoundingMode;
+
+/// A receipt line produced by `Money::receipt_line()`, where `subtotal + tax == total` exactly.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Receipt {
+	pub subtotal: Money,
+	pub tax: Money,
+	pub total: Money,
+}
+
+impl Money {
+	/// Returns the gross amount after adding VAT at `rate` (e.g. 0.20 for 20%), rounding to the cent with `mode`.
+	///
+	/// The result keeps the options of the Money instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::RoundingMode;
+	/// let net = Money::new(10, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(net.add_vat(0.20, RoundingMode::HalfUp).unwrap().to_string(), "$12.00");
+	/// ```
+	pub fn add_vat(&self, rate: f64, mode: RoundingMode) -> Result<Money, MoneyError> {
+		let mut m = convert_f64_to_money(convert_money_to_wide(self) as f64 * (1.0 + rate), mode)?;
+		m.options = self.options;
+		
+		Ok(m)
+	}
+	
+	/// Returns the net amount of a gross amount that includes VAT at `rate`, rounding to the cent with `mode`.
+	///
+	/// The result keeps the options of the Money instance.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::RoundingMode;
+	/// let gross = Money::new(12, 0, MoneySign::Positive).unwrap();
+	///
+	/// assert_eq!(gross.remove_vat(0.20, RoundingMode::HalfUp).unwrap().to_string(), "$10.00");
+	/// ```
+	pub fn remove_vat(&self, rate: f64, mode: RoundingMode) -> Result<Money, MoneyError> {
+		if 1.0 + rate == 0.0 {
+			return Err(MoneyError::DivideByZero);
+		}
+		
+		let mut m = convert_f64_to_money(convert_money_to_wide(self) as f64 / (1.0 + rate), mode)?;
+		m.options = self.options;
+		
+		Ok(m)
+	}
+	
+	/// Returns a receipt line for `subtotal` with tax at `tax_rate` (e.g. 0.08 for 8%), rounding the
+	/// tax to the cent with `mode`.
+	///
+	/// The total is the exact sum of the subtotal and the rounded tax, so the lines always add up.
+	/// All three amounts keep the options of `subtotal`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use nmoney::{Money, MoneySign};
+	/// # use nmoney::money::rounding::RoundingMode;
+	/// let subtotal = Money::new(19, 99, MoneySign::Positive).unwrap();
+	/// let receipt = Money::receipt_line(subtotal, 0.0825, RoundingMode::HalfUp).unwrap();
+	///
+	/// assert_eq!(receipt.tax.to_string(), "$1.65");
+	/// assert_eq!(receipt.total.to_string(), "$21.64");
+	/// ```
+	pub fn receipt_line(subtotal: Money, tax_rate: f64, mode: RoundingMode) -> Result<Receipt, MoneyError> {
+		let base = convert_money_to_wide(&subtotal);
+		let mut tax = convert_f64_to_money(base as f64 * tax_rate, mode)?;
+		let mut total = convert_wide_to_money(base + convert_money_to_wide(&tax))?;
+		
+		tax.options = subtotal.options;
+		total.options = subtotal.options;
+		
+		Ok(Receipt { subtotal, tax, total })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::MoneySign;
+	
+	#[test]
+	fn add_vat() {
+		let m = Money::new(19, 99, MoneySign::Positive).unwrap();
+		
+		assert_eq!(
+			m.add_vat(0.20, RoundingMode::HalfUp).unwrap(),
+			Money::new(23, 99, MoneySign::Positive).unwrap()
+		);
+	}
+	
+	#[test]
+	fn remove_vat_round_trip() {
+		for rate in [0.05, 0.07, 0.19, 0.20, 0.21, 0.25] {
+			for cents in [1, 99, 1999, 12345, 100000, 987654321] {
+				let m = Money::from_cents(cents);
+				let gross = m.add_vat(rate, RoundingMode::HalfUp).unwrap();
+				let net = gross.remove_vat(rate, RoundingMode::HalfUp).unwrap();
+				
+				assert!((net.as_cents().unwrap() - cents).abs() <= 1);
+			}
+		}
+	}
+	
+	#[test]
+	fn remove_vat_invalid_rate() {
+		let m = Money::new(12, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(m.remove_vat(-1.0, RoundingMode::HalfUp), Err(MoneyError::DivideByZero));
+		assert_eq!(m.add_vat(f64::NAN, RoundingMode::HalfUp), Err(MoneyError::NotFinite));
+	}
+	
+	#[test]
+	fn receipt_line_adds_up() {
+		for rate in [0.0, 0.05, 0.0725, 0.0825, 0.1, 0.19, 0.2, 0.333] {
+			for cents in [1, 99, 1999, 123457, -4321] {
+				let subtotal = Money::from_cents(cents);
+				let receipt = Money::receipt_line(subtotal, rate, RoundingMode::HalfEven).unwrap();
+				
+				assert!(receipt.subtotal.eq_exact(&subtotal));
+				assert_eq!(receipt.subtotal + receipt.tax, receipt.total);
+			}
+		}
+	}
+	
+	#[test]
+	fn receipt_line_keeps_options() {
+		let mut subtotal = Money::new(100, 0, MoneySign::Positive).unwrap();
+		subtotal.options.set_symbol('€');
+		let receipt = Money::receipt_line(subtotal, 0.19, RoundingMode::HalfUp).unwrap();
+		
+		assert_eq!(receipt.tax.to_string(), "€19.00");
+		assert_eq!(receipt.total.to_string(), "€119.00");
+	}
+	
+	#[test]
+	fn receipt_line_invalid() {
+		let subtotal = Money::new(100, 0, MoneySign::Positive).unwrap();
+		
+		assert_eq!(Money::receipt_line(subtotal, f64::NAN, RoundingMode::HalfUp), Err(MoneyError::NotFinite));
+		assert_eq!(Money::receipt_line(Money::max_representable(), 0.1, RoundingMode::HalfUp), Err(MoneyError::Overflow));
+	}
+}